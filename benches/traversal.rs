@@ -0,0 +1,216 @@
+//! Compares the empties and builds scans over the parallel traversal with the previous `WalkDir` implementations, on a
+//! synthetic tree
+//!
+//! * run with `cargo bench --bench traversal`, the tree is created in the temp folder and removed afterwards
+
+use std::collections::{BTreeSet, HashSet};
+use std::fs::{create_dir_all, read_dir, remove_dir_all, write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use rayon::ThreadPoolBuilder;
+use walkdir::WalkDir;
+
+use cleaner::{
+    scan_builds, scan_empties, AgeFilter, Configuration, EmptyCriteria, Platform, Selection, Traversal,
+    DEFAULT_PLATFORMS,
+};
+
+/// Folders per folder of the synthetic tree
+const FAN_OUT: usize = 6;
+
+/// Folder levels of the synthetic tree
+const DEPTH: usize = 5;
+
+/// Files per folder of the synthetic tree, leaf folders are left empty
+const FILES: usize = 4;
+
+/// Folder level of the synthetic tree made of rust projects, counted from the leaf folders
+const PROJECTS: usize = 2;
+
+/// Runs per measurement, the fastest run is reported
+const RUNS: usize = 5;
+
+fn main() {
+    let root = std::env::temp_dir().join(format!("cleaner-bench-{}", std::process::id()));
+    let platforms = serde_json::from_str::<Configuration>(DEFAULT_PLATFORMS).unwrap().platforms;
+
+    create_tree(&root, DEPTH);
+
+    let (folders, files) = WalkDir::new(&root).into_iter()
+        .filter_map(Result::ok)
+        .fold((0, 0), |(folders, files), entry| {
+            if entry.file_type().is_dir() { (folders + 1, files) } else { (folders, files + 1) }
+        });
+
+    let cores = std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+    let threads = [1, 2, 4, cores].into_iter().collect::<BTreeSet<_>>();
+
+    let (empties_baseline, empties) = fastest(|| reference_empties(&root, &platforms));
+    let (builds_baseline, builds) = fastest(|| reference_builds(&root, &platforms));
+
+    println!("synthetic tree of {folders} folders, {files} files, {empties} empty, {builds} with build artifacts");
+    println!();
+    println!("  {:<26} {empties_baseline:>10.2?}", "previous empties");
+
+    for &threads in &threads {
+        let pool = ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+        let (elapsed, found) = fastest(|| pool.install(|| scan_empties_count(&root, &platforms)));
+
+        assert_eq!(empties, found, "scan empties, {threads} thread(s)");
+
+        println!(
+            "  {:<26} {elapsed:>10.2?}  {:.2}x",
+            format!("scan empties, {threads} thread(s)"),
+            empties_baseline.as_secs_f64() / elapsed.as_secs_f64()
+        );
+    }
+
+    println!();
+    println!("  {:<26} {builds_baseline:>10.2?}", "previous builds");
+
+    for &threads in &threads {
+        let pool = ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+        let (elapsed, found) = fastest(|| pool.install(|| scan_builds_count(&root, &platforms)));
+
+        assert_eq!(builds, found, "scan builds, {threads} thread(s)");
+
+        println!(
+            "  {:<26} {elapsed:>10.2?}  {:.2}x",
+            format!("scan builds, {threads} thread(s)"),
+            builds_baseline.as_secs_f64() / elapsed.as_secs_f64()
+        );
+    }
+
+    remove_dir_all(&root).unwrap();
+}
+
+/// Creates a tree of folders with files, `FAN_OUT` wide and `depth` deep, leaf folders are left empty
+///
+/// * folders `PROJECTS` levels above the leaf folders are rust projects, with build artifacts
+fn create_tree(folder: &Path, depth: usize) {
+    create_dir_all(folder).unwrap();
+
+    if depth == 0 {
+        return;
+    }
+
+    for file in 0..FILES {
+        write(folder.join(format!("file-{file}.txt")), "synthetic").unwrap();
+    }
+
+    if depth == PROJECTS {
+        create_dir_all(folder.join("target")).unwrap();
+        write(folder.join("target").join("app"), "synthetic").unwrap();
+        write(folder.join("Cargo.toml"), "[package]").unwrap();
+    }
+
+    for idx in 0..FAN_OUT {
+        create_tree(&folder.join(format!("folder-{idx}")), depth - 1);
+    }
+}
+
+/// Measures the fastest of several runs, checking each run finds as many results as the first, returns the results
+fn fastest<F: FnMut() -> usize>(mut run: F) -> (Duration, usize) {
+    let mut expected = None;
+
+    let elapsed = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            let found = run();
+            let elapsed = start.elapsed();
+
+            assert_eq!(*expected.get_or_insert(found), found);
+
+            elapsed
+        })
+        .min()
+        .unwrap_or_default();
+
+    (elapsed, expected.unwrap_or_default())
+}
+
+/// Counts the empty folders of a tree with the previous empties search, starting a fresh walk under every folder
+fn reference_empties(root: &Path, platforms: &[Platform]) -> usize {
+    let skipped = platforms.iter().flat_map(|p| p.folders.iter().cloned()).collect::<HashSet<_>>();
+    let is_folder_empty = |path: &Path| WalkDir::new(path)
+        .min_depth(1)
+        .into_iter()
+        .all(|child| !child.unwrap().file_type().is_file());
+
+    let mut empties = 0;
+    let mut walker = WalkDir::new(root).into_iter();
+
+    while let Some(entry) = walker.next() {
+        let entry = entry.unwrap();
+
+        if entry.file_type().is_file() {
+            continue;
+        }
+
+        let folder_name = entry.file_name().to_string_lossy();
+
+        if folder_name == ".git" || skipped.contains(folder_name.as_ref()) {
+            walker.skip_current_dir();
+            continue;
+        }
+
+        if is_folder_empty(entry.path()) {
+            walker.skip_current_dir();
+            empties += 1;
+        }
+    }
+
+    empties
+}
+
+/// Counts the build artifacts of a tree with the previous builds search, reading the parent of every folder
+fn reference_builds(root: &Path, platforms: &[Platform]) -> usize {
+    let mut builds = 0;
+    let mut walker = WalkDir::new(root).into_iter();
+
+    while let Some(entry) = walker.next() {
+        let entry = entry.unwrap();
+
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+
+        let folder = entry.file_name().to_string_lossy().to_lowercase();
+        let parent = entry.path().parent().unwrap().to_string_lossy().to_lowercase();
+
+        let found = platforms.iter()
+            .filter(|platform| platform.folders.contains(&folder))
+            .take_while(|_| !parent.contains(folder.as_str()))
+            .any(|platform| read_dir(&parent).is_ok_and(|mut files| files.any(|file| {
+                file.is_ok_and(|file| {
+                    let file_name = file.file_name().to_string_lossy().to_lowercase();
+
+                    platform.associated.iter().any(|filter| filter.matches(&file_name))
+                })
+            })));
+
+        if found {
+            walker.skip_current_dir();
+            builds += 1;
+        }
+    }
+
+    builds
+}
+
+/// Counts the empty folders of a tree with a scan, over the parallel traversal shared by the walkers
+fn scan_empties_count(root: &Path, platforms: &[Platform]) -> usize {
+    let criteria = EmptyCriteria::default().with_hidden(true).with_git(false, true);
+
+    scan_empties(root, platforms, &Traversal::default(), &criteria)
+        .map(Result::unwrap)
+        .count()
+}
+
+/// Counts the build artifacts of a tree with a scan, over the parallel traversal shared by the walkers
+fn scan_builds_count(root: &Path, platforms: &[Platform]) -> usize {
+    scan_builds(root, &Selection::All, platforms, AgeFilter::default(), &Traversal::default(), false)
+        .map(Result::unwrap)
+        .count()
+}
@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::fs::{canonicalize, read_dir};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+
+use git2::{Branch, BranchType, ErrorCode, Reference, Repository, StatusOptions};
+use regex::Regex;
+
+use crate::models::{BranchName, Platform, RepoState, Selection};
+
+/// Matches main or master branch names
+static RE_MAIN_OR_MASTER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new("^(refs/heads/)?(main|master)$").unwrap());
+
+/// Classifies a folder as the build artifacts of a selected platform
+///
+/// * a folder is only classified if its project contains an associated file of the platform
+/// * folders nested in a folder of the same name are not classified
+pub fn classify_folder<'a, P: AsRef<Path>>(
+    folder: P, selection: &Selection, platforms: &'a [Platform],
+) -> Option<&'a Platform> {
+    let folder = folder.as_ref();
+    let name = folder.file_name()?.to_string_lossy().to_lowercase();
+    let project = folder.parent()?;
+    let parent = project.to_string_lossy().to_lowercase();
+
+    for platform in platforms.iter()
+        .filter(|p| selection.matches(&p.name) && p.folders.contains(&name))
+    {
+        if parent.contains(name.as_str()) {
+            break;
+        }
+
+        if let Ok(files) = read_dir(project)
+            && files
+                .filter(|v| {
+                    v.as_ref().is_ok_and(|file| {
+                        let file_name = file.file_name().to_string_lossy().to_lowercase();
+
+                        platform.associated.iter().any(|f| f.matches(&file_name))
+                    })
+                })
+                .count()
+                > 0
+        {
+            return Some(platform);
+        }
+    }
+
+    None
+}
+
+/// Checks if git would ignore a folder in its enclosing repository, none if there is no enclosing repository
+///
+/// * folders containing tracked files are never ignored, even if an ignore rule matches them
+///
+/// # Errors
+///
+/// * folder can not be resolved, or the enclosing repository or its index can not be read
+pub fn git_ignored<P: AsRef<Path>>(folder: P) -> Result<Option<bool>, git2::Error> {
+    GitRepos::default().ignored(folder.as_ref())
+}
+
+/// Checks if git tracks files in a folder in its enclosing repository, none if there is no enclosing repository
+///
+/// * files are tracked if they are in the index, even if they were deleted since
+///
+/// # Errors
+///
+/// * folder can not be resolved, or the enclosing repository or its index can not be read
+pub fn git_tracked<P: AsRef<Path>>(folder: P) -> Result<Option<bool>, git2::Error> {
+    GitRepos::default().tracked(folder.as_ref())
+}
+
+/// Classifies the state of a repo's checked out branch
+#[must_use]
+pub fn classify_repo(repo: &Repository) -> RepoState {
+    let head = repo.head().ok();
+
+    let ahead_behind = || {
+        let branch = Branch::wrap(repo.head().ok()?);
+        let upstream = branch.upstream().ok()?;
+        let oid = branch.get().target()?;
+        let upstream_oid = upstream.get().target()?;
+
+        repo.graph_ahead_behind(oid, upstream_oid).ok()
+    };
+
+    let (ahead, behind) = ahead_behind().unzip();
+
+    RepoState {
+        branch: head.as_ref().map(|head| branch_name(head).to_string()),
+        detached: repo.head_detached().unwrap_or_default(),
+        changes: uncommitted_changes(repo).ok(),
+        ahead,
+        behind,
+    }
+}
+
+/// Names the branch a reference points to, or the commit of a detached head
+#[must_use]
+pub fn branch_name<'a>(reference: &'a Reference) -> BranchName<'a> {
+    let name = reference.shorthand().unwrap_or_default();
+
+    if name == "HEAD"
+        && let Some(oid) = reference.target() {
+        return BranchName::Head(oid);
+    }
+
+    BranchName::Branch(name)
+}
+
+/// Checks if a repo has a local branch by name
+///
+/// # Errors
+///
+/// * branches of the repo can not be read
+pub fn has_local_branch(repo: &Repository, name: &str) -> Result<bool, git2::Error> {
+    for branch in repo.branches(Some(BranchType::Local))? {
+        let Ok((branch, _)) = branch else {
+            continue;
+        };
+
+        if branch.name().unwrap_or_default() == Some(name) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Checks if a repo's checked out branch is not main or master
+///
+/// # Errors
+///
+/// * head of the repo can not be read, including if nothing has been committed
+pub fn is_branched(repo: &Repository) -> Result<bool, git2::Error> {
+    Ok(repo.head()?.name().is_some_and(|name| !is_main_branch(name)))
+}
+
+/// Checks if a repo has been initialized, but nothing has been committed
+#[must_use]
+pub fn is_init_only(repo: &Repository) -> bool {
+    repo.head().is_err_and(|err| err.code() == ErrorCode::UnbornBranch)
+}
+
+/// Checks if a branch name is main or master
+pub fn is_main_branch(name: &str) -> bool {
+    RE_MAIN_OR_MASTER.is_match(name)
+}
+
+/// Counts a repo's uncommitted changes, including untracked files
+///
+/// # Errors
+///
+/// * status of the repo can not be read
+pub fn uncommitted_changes(repo: &Repository) -> Result<usize, git2::Error> {
+    let mut options = StatusOptions::new();
+
+    Ok(repo.statuses(Some(options.include_untracked(true)))?.len())
+}
+
+/// Repositories enclosing the folders of a scan, by their git folder
+///
+/// * each repository is opened, and its index read, once per scan instead of once per checked folder
+/// * folders are checked in parallel, a repository is checked by one thread at a time
+#[derive(Default)]
+pub struct GitRepos(Mutex<HashMap<PathBuf, Arc<Mutex<OpenRepo>>>>);
+
+/// Repository opened during a scan, with the paths of its index once they are needed
+struct OpenRepo {
+    /// Opened repository
+    repo: Repository,
+
+    /// Sorted paths of the files in the repository's index, relative to its work folder
+    tracked: Option<Vec<Vec<u8>>>,
+}
+
+impl GitRepos {
+    /// Checks if git would ignore a folder in its enclosing repository, see [`git_ignored`]
+    pub fn ignored(&self, folder: &Path) -> Result<Option<bool>, git2::Error> {
+        self.check(folder, |open, relative| {
+            if relative.as_os_str().is_empty() || !open.repo.is_path_ignored(relative)? {
+                return Ok(false);
+            }
+
+            Ok(!open.has_tracked_files(relative)?)
+        })
+    }
+
+    /// Checks if git tracks files in a folder in its enclosing repository, see [`git_tracked`]
+    pub fn tracked(&self, folder: &Path) -> Result<Option<bool>, git2::Error> {
+        self.check(folder, |open, relative| {
+            if relative.as_os_str().is_empty() {
+                return Ok(true);
+            }
+
+            open.has_tracked_files(relative)
+        })
+    }
+
+    /// Checks a folder, relative to the work folder of its enclosing repository, none if there is no enclosing repository
+    fn check<F>(&self, folder: &Path, check: F) -> Result<Option<bool>, git2::Error>
+    where
+        F: FnOnce(&mut OpenRepo, &Path) -> Result<bool, git2::Error>,
+    {
+        let folder = canonicalize(folder).map_err(|err| git2::Error::from_str(&err.to_string()))?;
+
+        let git_folder = match Repository::discover_path(&folder, None::<&str>) {
+            Ok(git_folder) => git_folder,
+            Err(err) if err.code() == ErrorCode::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+
+        let open = self.open(git_folder)?;
+        let mut open = open.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let Some(relative) = open.repo.workdir()
+            .and_then(|workdir| folder.strip_prefix(workdir).ok())
+            .map(Path::to_path_buf) else {
+            return Ok(None);
+        };
+
+        let checked = check(&mut open, &relative).map(Some);
+
+        drop(open);
+
+        checked
+    }
+
+    /// Opens a repository by its git folder, unless it was already opened during the scan
+    fn open(&self, git_folder: PathBuf) -> Result<Arc<Mutex<OpenRepo>>, git2::Error> {
+        let mut repos = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(open) = repos.get(&git_folder) {
+            return Ok(Arc::clone(open));
+        }
+
+        let open = Arc::new(Mutex::new(OpenRepo { repo: Repository::open(&git_folder)?, tracked: None }));
+
+        repos.insert(git_folder, Arc::clone(&open));
+
+        drop(repos);
+
+        Ok(open)
+    }
+}
+
+impl OpenRepo {
+    /// Checks if the index of the repository has files in a folder, relative to the repository's work folder
+    fn has_tracked_files(&mut self, relative: &Path) -> Result<bool, git2::Error> {
+        let prefix = relative.iter()
+            .map(|part| part.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        let prefix = format!("{prefix}/").into_bytes();
+
+        let tracked = match &mut self.tracked {
+            Some(tracked) => tracked,
+            tracked => {
+                let mut paths = self.repo.index()?.iter().map(|entry| entry.path).collect::<Vec<_>>();
+
+                paths.sort_unstable();
+
+                tracked.insert(paths)
+            }
+        };
+
+        let first = tracked.partition_point(|path| *path < prefix);
+
+        Ok(tracked.get(first).is_some_and(|path| path.starts_with(&prefix)))
+    }
+}
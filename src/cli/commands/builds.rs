@@ -1,4 +1,4 @@
-use crate::cli::selection::Selection;
+use cleaner::{Age, AgeFilter, Selection, Size};
 
 /// Builds subcommand for handling build artifacts for supported platforms
 #[derive(Debug, Eq, PartialEq, Subcommand)]
@@ -6,22 +6,35 @@ pub enum Builds {
     /// List matching build artifacts
     #[clap(alias = "ls")]
     List {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
 
         /// Optionally specify supported development platform(s), defaults to "all"
         ///
         /// * use "supported" command to see a list of all supported development platforms
         #[clap(required = false, short, long, verbatim_doc_comment, default_value_t = Selection::All)]
         types: Selection,
+
+        #[clap(flatten)]
+        criteria: ArtifactFilter,
+
+        /// Lists folders skipped by ignore rules, and the rule that skipped them
+        ///
+        /// * rules are read from .cleanerignore files and the configuration
+        #[clap(long, verbatim_doc_comment)]
+        show_ignored: bool,
     },
     /// Remove matching build artifacts
     #[clap(alias = "rm")]
     Remove {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
 
         /// Optionally specify supported development platform(s), defaults to "all"
         ///
@@ -29,17 +42,88 @@ pub enum Builds {
         #[clap(short, long, verbatim_doc_comment, default_value_t = Selection::All)]
         types: Selection,
 
+        #[clap(flatten)]
+        criteria: ArtifactFilter,
+
+        /// Removes least recently used build artifacts until at least this much space is freed
+        ///
+        /// * i.e. 500M, 10G or 1.5GiB, units are binary multiples, must be more than 0
+        /// * projects without files of their own have no age, their build artifacts are removed last
+        #[clap(long, verbatim_doc_comment, value_parser = parse_free)]
+        free: Option<Size>,
+
         /// Executes remove action without confirmation, defaults to interactive confirmation
         #[clap(short = 'y', long, verbatim_doc_comment)]
         confirmed: bool,
+
+        /// Picks folders to remove from a filterable list with their sizes and ages, instead of confirming each one
+        #[clap(short = 'i', long, verbatim_doc_comment, conflicts_with_all = ["confirmed", "free"])]
+        interactive: bool,
+
+        /// Preselects folders older than this age in the interactive list, i.e. 30d or 6w
+        ///
+        /// * age units: w(eeks), d(ays), h(ours), m(inutes) & s(econds)
+        #[clap(long, verbatim_doc_comment, default_value = "30d")]
+        stale: Age,
+
+        /// Lists what would be removed, with sizes, without removing anything
+        #[clap(short = 'n', long, verbatim_doc_comment)]
+        dry_run: bool,
+
+        /// Moves folders to the trash instead of permanently deleting them
+        #[clap(long, verbatim_doc_comment, conflicts_with = "dry_run")]
+        trash: bool,
     },
 }
 
 impl Default for Builds {
     fn default() -> Self {
         Self::List {
-            path: String::from('.'),
+            paths: vec![String::from('.')],
             types: Selection::All,
+            criteria: ArtifactFilter::default(),
+            show_ignored: false,
         }
     }
 }
+
+/// Options narrowing down which build artifacts are included
+#[derive(Args, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ArtifactFilter {
+    /// Only include projects without changes, outside build artifacts, within an age, i.e. 30d or 6w
+    ///
+    /// * age units: w(eeks), d(ays), h(ours), m(inutes) & s(econds)
+    #[clap(long, verbatim_doc_comment)]
+    pub older_than: Option<Age>,
+
+    /// Only include projects with changes, outside build artifacts, within an age, i.e. 30d or 6w
+    ///
+    /// * age units: w(eeks), d(ays), h(ours), m(inutes) & s(econds)
+    #[clap(long, verbatim_doc_comment)]
+    pub newer_than: Option<Age>,
+
+    /// Only include build artifacts git would ignore in their enclosing repository
+    ///
+    /// * folders with files tracked by git are skipped, even if their name matches a platform
+    /// * build artifacts outside of a git repository are included
+    #[clap(long, verbatim_doc_comment)]
+    pub respect_gitignore: bool,
+}
+
+impl ArtifactFilter {
+    /// Age filter of the included projects
+    pub const fn age(&self) -> AgeFilter {
+        AgeFilter { older_than: self.older_than, newer_than: self.newer_than }
+    }
+}
+
+/// Parses the space to free, nothing to free is rejected, it would never remove anything
+pub fn parse_free(src: &str) -> Result<Size, String> {
+    let free = src.parse::<Size>()?;
+
+    if free == Size::default() {
+        return Err(String::from("nothing to free, expected a size more than 0"));
+    }
+
+    Ok(free)
+}
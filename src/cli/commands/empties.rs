@@ -1,30 +1,72 @@
+use cleaner::Age;
+
 /// Empties subcommand for handling empty folders
 #[derive(Debug, Eq, PartialEq, Subcommand)]
 pub enum Empties {
     /// List matching empty folders
     #[clap(alias = "ls")]
     List {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
 
-        /// Includes empty hidden folders, i.e. folders that start with a '.'
-        #[clap(short = 's', long, verbatim_doc_comment)]
-        hidden: bool,
+        #[clap(flatten)]
+        criteria: EmptiesFilter,
     },
     /// Remove matching empty folders
     #[clap(alias = "rm")]
     Remove {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
 
         /// Executes remove action without confirmation, defaults to interactive confirmation
         #[clap(short = 'y', long, verbatim_doc_comment)]
         confirmed: bool,
 
-        /// Includes empty hidden folders, i.e. folders that start with a '.'
-        #[clap(short = 's', long, verbatim_doc_comment)]
-        hidden: bool,
+        /// Picks folders to remove from a filterable list with their sizes and ages, instead of confirming each one
+        #[clap(short = 'i', long, verbatim_doc_comment, conflicts_with = "confirmed")]
+        interactive: bool,
+
+        /// Preselects folders older than this age in the interactive list, i.e. 30d or 6w
+        ///
+        /// * age units: w(eeks), d(ays), h(ours), m(inutes) & s(econds)
+        #[clap(long, verbatim_doc_comment, default_value = "30d")]
+        stale: Age,
+
+        #[clap(flatten)]
+        criteria: EmptiesFilter,
+
+        /// Lists what would be removed, with sizes, without removing anything
+        #[clap(short = 'n', long, verbatim_doc_comment)]
+        dry_run: bool,
+
+        /// Moves folders to the trash instead of permanently deleting them
+        #[clap(long, verbatim_doc_comment, conflicts_with = "dry_run")]
+        trash: bool,
     },
 }
+
+/// Options narrowing down which empty folders are included
+#[derive(Args, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct EmptiesFilter {
+    /// Includes empty hidden folders, i.e. folders that start with a '.'
+    #[clap(short = 's', long, verbatim_doc_comment)]
+    pub hidden: bool,
+
+    /// Only include empty folders git would ignore in their enclosing repository
+    ///
+    /// * empty folders outside of a git repository are included
+    #[clap(long, verbatim_doc_comment)]
+    pub respect_gitignore: bool,
+
+    /// Includes empty folders tracked by git, or marked with a placeholder file, i.e. .gitkeep or .keep
+    ///
+    /// * a placeholder marks the folder it is in, and the folders next to it
+    #[clap(long, verbatim_doc_comment)]
+    pub include_tracked: bool,
+}
@@ -0,0 +1,55 @@
+use cleaner::{Age, Selection};
+
+/// History command arguments for listing removals recorded in the removal journal
+#[derive(Args, Clone, Debug, Eq, PartialEq)]
+pub struct History {
+    /// Optionally specify location of removed folders, defaults to all locations
+    #[clap(required = false, verbatim_doc_comment)]
+    pub path: Option<String>,
+
+    /// Optionally specify development platform(s) of removed build artifacts, defaults to "all"
+    ///
+    /// * empty folders are only included for "all"
+    #[clap(short, long, verbatim_doc_comment, default_value_t = Selection::All)]
+    pub types: Selection,
+
+    /// Only include removals within an age, i.e. 30d or 6w
+    ///
+    /// * age units: w(eeks), d(ays), h(ours), m(inutes) & s(econds)
+    #[clap(long, verbatim_doc_comment)]
+    pub within: Option<Age>,
+
+    /// Totals the space reclaimed in each period, instead of listing removals
+    #[clap(long, value_enum, verbatim_doc_comment)]
+    pub by: Option<Period>,
+}
+
+/// Period removals are totalled by
+#[derive(Copy, Clone, Debug, Eq, PartialEq, ValueEnum)]
+#[clap(verbatim_doc_comment)]
+pub enum Period {
+    /// Totals each day, i.e. 2024-08-31
+    #[clap(verbatim_doc_comment)]
+    Day,
+    /// Totals each month, i.e. 2024-08
+    #[clap(verbatim_doc_comment)]
+    Month,
+    /// Totals each year, i.e. 2024
+    #[clap(verbatim_doc_comment)]
+    Year,
+}
+
+impl Period {
+    /// Period of a journal timestamp, i.e. `2024-08` of `2024-08-31T22:32:08+02:00` for a month
+    ///
+    /// * timestamps are local date and time, with or without an offset, periods are the local dates they start with
+    pub fn of(self, timestamp: &str) -> &str {
+        let length = match self {
+            Self::Day => 10,
+            Self::Month => 7,
+            Self::Year => 4,
+        };
+
+        timestamp.get(..length).unwrap_or(timestamp)
+    }
+}
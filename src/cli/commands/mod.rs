@@ -1,29 +1,96 @@
 use crate::cli::commands::builds::Builds;
 use crate::cli::commands::empties::Empties;
+use crate::cli::commands::history::History;
 use crate::cli::commands::repos::Repos;
+use crate::cli::commands::restore::Restore;
 use crate::cli::commands::supported::Supported;
 
 pub mod builds;
 pub mod empties;
+pub mod history;
 pub mod repos;
+pub mod restore;
 pub mod supported;
 
 /// `cleaner` subcommands defined
 #[derive(Debug, Eq, PartialEq, Subcommand)]
 pub enum Commands {
     /// Manage build artifacts of supported platforms
-    #[clap(subcommand)]
-    Builds(Builds),
+    Builds {
+        #[clap(flatten)]
+        traversal: TraversalOptions,
+
+        #[clap(subcommand)]
+        command: Builds,
+    },
 
     /// Manage empty folders
-    #[clap(subcommand)]
-    Empties(Empties),
+    Empties {
+        #[clap(flatten)]
+        traversal: TraversalOptions,
+
+        #[clap(subcommand)]
+        command: Empties,
+    },
+
+    /// List removals recorded in the removal journal, and the space they reclaimed
+    History(History),
 
     /// Search through repos
+    Repos {
+        #[clap(flatten)]
+        traversal: TraversalOptions,
+
+        #[clap(subcommand)]
+        command: Repos,
+    },
+
+    /// Restore folders cleaner moved to the trash
     #[clap(subcommand)]
-    Repos(Repos),
+    Restore(Restore),
 
     /// Manage supported development platforms
     #[clap(subcommand)]
     Supported(Supported),
 }
+
+impl Commands {
+    /// Traversal options of commands that search folders
+    pub const fn traversal(&self) -> Option<&TraversalOptions> {
+        match self {
+            Self::Builds { traversal, .. } | Self::Empties { traversal, .. } | Self::Repos { traversal, .. } =>
+                Some(traversal),
+            Self::History(_) | Self::Restore(_) | Self::Supported(_) => None,
+        }
+    }
+
+}
+
+/// Options limiting which folders are searched
+#[derive(Args, Clone, Debug, Default, Eq, PartialEq)]
+#[command(next_help_heading = "Search Options")]
+pub struct TraversalOptions {
+    /// Only include results at most this many folders below the target path
+    #[clap(long, global = true, verbatim_doc_comment)]
+    pub max_depth: Option<usize>,
+
+    /// Only include results at least this many folders below the target path
+    #[clap(long, global = true, verbatim_doc_comment, default_value_t = 0)]
+    pub min_depth: usize,
+
+    /// Never search folders matching a glob, can be repeated, i.e. --exclude .cache --exclude "/mnt/*"
+    ///
+    /// * globs match a folder's name, its path relative to the target path or its absolute path
+    #[clap(long, global = true, verbatim_doc_comment, value_name = "GLOB")]
+    pub exclude: Vec<String>,
+
+    /// Searches folders symbolic links point to, links that loop back to a folder containing them are reported
+    ///
+    /// * removing a symbolic link only unlinks it, the folder it points to is never deleted
+    #[clap(short = 'L', long, global = true, verbatim_doc_comment)]
+    pub follow_links: bool,
+
+    /// Does not search folders on other file systems than the target path, i.e. mounted network drives
+    #[clap(short = 'x', long, global = true, verbatim_doc_comment)]
+    pub one_file_system: bool,
+}
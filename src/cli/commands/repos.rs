@@ -4,72 +4,92 @@ pub enum Repos {
     /// List repositories not in master or main
     #[command(alias = "br")]
     Branched {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
     },
     /// List repositories with uncommitted changes
     #[command(alias = "chg")]
     Changes {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
     },
     /// List detached repositories, HEAD
     #[command(alias = "de")]
     Detached {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
     },
     /// List repositories with errors
     #[command(alias = "err")]
     Error {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
     },
     /// List repositories that are only initialized (unborn)
     #[command(alias = "unborn", alias = "ub", alias = "i")]
     Init {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
     },
     /// List repositories
     #[command(alias = "ls")]
     List {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
     },
     /// List repositories with no remotes configured
     #[command(alias = "lcl")]
     Local {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
     },
     /// List repositories with a "Main" branch
     #[command(alias = "mn")]
     Main {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
     },
     /// List repositories with a "Master" branch
     #[command(alias = "ms")]
     Master {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
     },
     /// List outdated repos
     #[command(alias = "od")]
     Outdated {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
 
         /// Filters outdated repos 
         #[clap(required = false, short = 'f', long, verbatim_doc_comment, default_value = "either")]
@@ -82,9 +102,11 @@ pub enum Repos {
     /// List repositories that are up-to-date
     #[command(alias = "utd", alias = "synced")]
     UpToDate {
-        /// Optionally specify target path, defaults to current folder
-        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
-        path: String,
+        /// Optionally specify target path(s), defaults to current folder
+        ///
+        /// * folders found in more than one target path are only included for the first
+        #[clap(required = false, verbatim_doc_comment, default_value = ".", value_name = "PATH")]
+        paths: Vec<String>,
 
         /// Only check repo's Main branch
         #[clap(short = 'm', long, verbatim_doc_comment)]
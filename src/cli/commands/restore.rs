@@ -0,0 +1,22 @@
+/// Restore subcommand for handling folders cleaner moved to the trash
+#[derive(Debug, Eq, PartialEq, Subcommand)]
+pub enum Restore {
+    /// List folders cleaner moved to the trash
+    #[clap(alias = "ls")]
+    List {
+        /// Optionally specify original location of trashed folders, defaults to current folder
+        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
+        path: String,
+    },
+    /// Restore folders cleaner moved to the trash, to their original location
+    #[clap(alias = "rs")]
+    Items {
+        /// Optionally specify original location of trashed folders, defaults to current folder
+        #[clap(required = false, verbatim_doc_comment, default_value = ".")]
+        path: String,
+
+        /// Executes restore action without confirmation, defaults to interactive confirmation
+        #[clap(short = 'y', long, verbatim_doc_comment)]
+        confirmed: bool,
+    },
+}
@@ -4,7 +4,13 @@
 pub enum Supported {
     /// List configured development platforms
     #[clap(alias = "ls", verbatim_doc_comment)]
-    List,
+    List {
+        /// Lists the configuration layer each platform came from, instead of its details
+        ///
+        /// * layers: default, system, user & project, later layers override earlier layers
+        #[clap(long, verbatim_doc_comment)]
+        origin: bool,
+    },
 
     /// Show path of platform configuration file
     #[clap(alias = "show", verbatim_doc_comment)]
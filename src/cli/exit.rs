@@ -0,0 +1,36 @@
+use std::process::ExitCode;
+
+/// Process exit codes of cleaner, ordered by precedence
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Exit {
+    /// Command succeeded and found matches, `0`
+    Found = 0,
+
+    /// Command succeeded but did not find any matches, `1`
+    NotFound = 1,
+
+    /// Invalid arguments or configuration, `2`
+    Invalid = 2,
+
+    /// Command completed but some removals or repo operations failed, `3`
+    PartialFailure = 3,
+
+    /// User cancelled the command, `4`
+    Cancelled = 4,
+
+    /// Command failed while running, i.e. reading the trash, the journal or input failed, `5`
+    Failed = 5,
+}
+
+impl Exit {
+    /// Determines exit based on whether matches were found
+    pub const fn found(found: bool) -> Self {
+        if found { Self::Found } else { Self::NotFound }
+    }
+}
+
+impl From<Exit> for ExitCode {
+    fn from(exit: Exit) -> Self {
+        Self::from(exit as u8)
+    }
+}
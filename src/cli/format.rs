@@ -0,0 +1,18 @@
+/// Output format of command results
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, ValueEnum)]
+#[clap(verbatim_doc_comment)]
+pub enum Format {
+    /// Human readable text (Default Value)
+    #[default]
+    #[clap(verbatim_doc_comment)]
+    Text,
+    /// A single JSON array of records
+    #[clap(verbatim_doc_comment)]
+    Json,
+    /// One JSON record per line
+    #[clap(verbatim_doc_comment)]
+    Ndjson,
+    /// Comma separated values with a header
+    #[clap(verbatim_doc_comment)]
+    Csv,
+}
@@ -1,9 +1,15 @@
 #![allow(clippy::upper_case_acronyms)]
 
+use std::num::NonZeroUsize;
+
 use crate::cli::commands::Commands;
+use crate::cli::format::Format;
 
 pub mod commands;
-pub mod selection;
+pub mod exit;
+pub mod format;
+pub mod prompt;
+pub mod record;
 
 /// Utility for cleaning build artifacts in bulk
 ///
@@ -16,4 +22,16 @@ pub struct CLI {
     /// Defines cli commands
     #[clap(subcommand)]
     pub commands: Commands,
+
+    /// Output format of results, errors are written to stderr in the same format
+    #[clap(long, global = true, value_enum, verbatim_doc_comment, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Stops at the first error, defaults to collecting errors and reporting them at the end
+    #[clap(long, global = true, verbatim_doc_comment)]
+    pub fail_fast: bool,
+
+    /// Number of threads searching folders in parallel, defaults to the number of logical cores
+    #[clap(long, global = true, verbatim_doc_comment)]
+    pub threads: Option<NonZeroUsize>,
 }
@@ -0,0 +1,43 @@
+use cleaner::Age;
+
+/// How removal of matching folders is confirmed
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Prompt {
+    /// Asks for confirmation of each folder
+    Each,
+
+    /// Removes folders without confirmation
+    Confirmed,
+
+    /// Lets the user pick folders to remove from a list, folders older than an age are preselected
+    Pick(Age),
+}
+
+impl Prompt {
+    /// Determines prompt from command line switches, confirmed takes precedence
+    pub const fn new(confirmed: bool, interactive: bool, stale: Age) -> Self {
+        match (confirmed, interactive) {
+            (true, _) => Self::Confirmed,
+            (false, true) => Self::Pick(stale),
+            (false, false) => Self::Each,
+        }
+    }
+
+    /// Age of folders preselected for removal, if folders are picked from a list
+    pub const fn picker(self) -> Option<Age> {
+        match self {
+            Self::Pick(stale) => Some(stale),
+            Self::Each | Self::Confirmed => None,
+        }
+    }
+
+    /// Checks if folders are removed without asking for each one
+    pub const fn is_confirmed(self) -> bool {
+        !matches!(self, Self::Each)
+    }
+
+    /// Checks if folders are removed without the user confirming or picking them
+    pub const fn is_automatic(self) -> bool {
+        matches!(self, Self::Confirmed)
+    }
+}
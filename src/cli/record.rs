@@ -0,0 +1,164 @@
+use std::path::{absolute, Path};
+
+use serde::Serialize;
+
+use cleaner::Error;
+
+/// Kind of result a record describes
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordKind {
+    /// Build artifact folder of a supported platform
+    #[default]
+    Artifact,
+
+    /// Platform configuration file
+    Configuration,
+
+    /// Empty folder
+    Empty,
+
+    /// Error that occurred while processing a command
+    Error,
+
+    /// Folder skipped by an ignore rule
+    Ignored,
+
+    /// Supported development platform
+    Platform,
+
+    /// Space reclaimed by removals in a period, i.e. a day
+    Reclaimed,
+
+    /// Folder cleaner removed, as recorded in the removal journal
+    Removed,
+
+    /// Git repository
+    Repo,
+
+    /// Folder cleaner moved to the trash
+    Trashed,
+}
+
+/// Structured description of a command result, used for machine readable output
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Record {
+    /// Kind of result
+    pub kind: RecordKind,
+
+    /// Development platform name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+
+    /// Absolute path of result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+
+    /// Path of result relative to the searched path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relative_path: Option<String>,
+
+    /// On disk size in bytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+
+    /// Number of junk files in an empty folder, i.e. `.DS_Store`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub junk: Option<usize>,
+
+    /// Seconds since the project was last modified, outside its build artifacts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub age: Option<u64>,
+
+    /// Action applied to the result, i.e. removed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Current branch, or detached head, of a repository
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+
+    /// Number of uncommitted changes in a repository
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<usize>,
+
+    /// Number of commits the current branch is ahead of its upstream
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ahead: Option<usize>,
+
+    /// Number of commits the current branch is behind its upstream
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub behind: Option<usize>,
+
+    /// Local date and time a folder was trashed, or removed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted: Option<String>,
+
+    /// Build artifact folder names of a platform
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folders: Option<Vec<String>>,
+
+    /// Associated files and file extensions of a platform
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub associated: Option<Vec<String>>,
+
+    /// Descriptive message, i.e. an error
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl Record {
+    /// Column names of records in the order they are serialized
+    pub const COLUMNS: [&'static str; 16] = [
+        "kind", "platform", "path", "relative_path", "size", "junk", "age", "status", "branch",
+        "changes", "ahead", "behind", "deleted", "folders", "associated", "message",
+    ];
+
+    /// Initializes a record of a kind
+    #[must_use]
+    pub fn new(kind: RecordKind) -> Self {
+        Self { kind, ..Self::default() }
+    }
+
+    /// Initializes an error record
+    pub fn error(message: impl Into<String>) -> Self {
+        Self { message: Some(message.into()), ..Self::new(RecordKind::Error) }
+    }
+
+    /// Sets absolute path and path relative to a searched root
+    #[must_use]
+    pub fn with_path<P: AsRef<Path>, R: AsRef<Path>>(mut self, path: P, root: R) -> Self {
+        let path = path.as_ref();
+
+        self.path = Some(absolute(path).unwrap_or_else(|_| path.to_path_buf()).to_string_lossy().to_string());
+        self.relative_path = path.strip_prefix(root).ok().map(|p| p.to_string_lossy().to_string());
+
+        self
+    }
+
+    /// Sets an error message, marking the record as an error
+    #[must_use]
+    pub fn with_error(mut self, message: impl Into<String>) -> Self {
+        self.kind = RecordKind::Error;
+        self.message = Some(message.into());
+
+        self
+    }
+
+    /// Sets the action applied to the result
+    #[must_use]
+    pub fn with_status(mut self, status: &impl ToString) -> Self {
+        self.status = Some(status.to_string());
+
+        self
+    }
+}
+
+impl From<&Error> for Record {
+    fn from(err: &Error) -> Self {
+        Self {
+            path: err.path().map(|path| path.to_string_lossy().to_string()),
+            ..Self::error(err.to_string())
+        }
+    }
+}
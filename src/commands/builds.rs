@@ -1,85 +1,302 @@
-use std::fs::remove_dir_all;
-use std::path::{Path, MAIN_SEPARATOR};
+use std::path::Path;
+use std::time::SystemTime;
 
-use crate::commands::walkers::BuildsWalker;
-use crate::models::BuildArtifacts;
+use rayon::prelude::*;
+
+use cleaner::{
+    disk_usage, last_activity, remove_and_record, scan_builds, Age, BuildArtifacts, Error, Ignored, JournalEntry,
+    Platform, Removal, Selection, Size,
+};
+
+use crate::cli::commands::builds::ArtifactFilter;
+use crate::cli::exit::Exit;
+use crate::cli::prompt::Prompt;
+use crate::cli::record::{Record, RecordKind};
 use crate::utils::{
-    display_error_and_exit, get_confirmation, validate_path, validate_platforms_filter,
+    get_confirmation, handle_interrupts, is_interrupted, output, output_record, output_text, pick_items,
+    report_error, traversal_of, validate_platforms_filter, Roots,
 };
-use crate::{Platform, Selection};
 
-/// Lists matching build artifacts
-pub fn list_build_artifacts<P: AsRef<Path>>(path: P, filter: &Selection, platforms: &[Platform]) {
-    build_artifacts_handler("list", path, filter, platforms, |_, msg| {
-        println!("  - {msg}");
+/// Lists matching build artifacts, optionally with folders skipped by ignore rules
+pub fn list_build_artifacts(
+    paths: &[String],
+    filter: &Selection,
+    criteria: ArtifactFilter,
+    show_ignored: bool,
+) -> Exit {
+    let roots = Roots::new(paths);
+
+    let (exit, _, ignored) = build_artifacts_handler(&roots, filter, criteria, None, None, |_, msg, record| {
+        output(format!("  - {msg}"), record);
 
-        Ok(())
+        Ok(true)
     });
+
+    if show_ignored && !ignored.is_empty() {
+        output_text("\n  Ignored");
+
+        for (root, ignored) in ignored {
+            let record = Record {
+                message: Some(ignored.to_string()),
+                ..Record::new(RecordKind::Ignored)
+            }.with_path(&ignored.path, root);
+
+            output(format!("  - {} - {ignored}", roots.display(&ignored.path)), record);
+        }
+    }
+
+    exit
 }
 
-/// Removes matching build artifacts
-pub fn remove_build_artifacts<P: AsRef<Path>>(
-    path: P,
+/// Removes matching build artifacts, each removal is recorded in the removal journal
+pub fn remove_build_artifacts(
+    paths: &[String],
     filter: &Selection,
-    platforms: &[Platform],
-    confirmed: bool,
-) {
-    build_artifacts_handler("remove", path, filter, platforms, move |artifact, msg| {
-        if confirmed || get_confirmation(&msg) {
-            remove_dir_all(&artifact.folder).map_err(|err| format!("{err}"))?;
-            if confirmed {
-                println!("  - {msg} - removed");
+    criteria: ArtifactFilter,
+    free: Option<Size>,
+    prompt: Prompt,
+    removal: Removal,
+) -> Exit {
+    let handler = move |artifact: &BuildArtifacts, msg: &str, record: Record| {
+        if removal == Removal::DryRun {
+            output(format!("  - {msg} - {removal}"), record.with_status(&removal));
+
+            return Ok(true);
+        }
+
+        if prompt.is_confirmed() || get_confirmation("remove", msg) {
+            let entry = JournalEntry::new(
+                &artifact.folder, Some(artifact.name.to_string()), record.size.unwrap_or_default(),
+                !prompt.is_automatic(),
+            );
+
+            // a folder that was removed is reported as removed, even if it could not be recorded
+            match remove_and_record(entry, removal) {
+                Err(err @ Error::Journal { .. }) => report_error(err),
+                result => result?,
             }
+
+            if prompt.is_confirmed() {
+                output(format!("  - {msg} - {removal}"), record.with_status(&removal));
+            } else {
+                output_record(record.with_status(&removal));
+            }
+
+            return Ok(true);
         }
 
-        Ok(())
-    });
+        Ok(false)
+    };
+
+    handle_interrupts();
+
+    let roots = Roots::new(paths);
+    let (mut exit, totals, _) =
+        build_artifacts_handler(&roots, filter, criteria, free, prompt.picker(), handler);
+
+    if let Some(free) = free {
+        let reclaimed = totals.into_iter().sum::<Size>();
+        let shortfall = if reclaimed < free { ", target not reached" } else { "" };
+        let freed = if removal == Removal::DryRun { "Would free" } else { "Freed" };
+
+        output_text(format!("\n{freed} {reclaimed} of {free} requested{shortfall}"));
+    }
+
+    if is_interrupted() {
+        output_text("\nCancelled, the remaining build artifacts were not removed");
+
+        exit = Exit::Cancelled;
+    }
+
+    if removal == Removal::DryRun {
+        output_text("\nDry run, nothing was removed");
+    }
+
+    exit
 }
 
-/// Common build artifact handling logic
-fn build_artifacts_handler<F, P: AsRef<Path>>(
-    action: &str,
-    path: P,
+/// Common build artifact handling logic, handler returns whether an artifact counts towards totals
+///
+/// * each root is searched for the platforms of its own configuration, totals list the platforms of all roots
+/// * when freeing space, artifacts of all roots are handled least recently used first, until enough is handled,
+///   artifacts of projects without files of their own last
+/// * when picking, only artifacts the user picked are handled, artifacts of projects older than the age are preselected
+/// * when interrupted, searching and handling stop, totals of the artifacts handled so far are displayed
+/// * failures are reported at the end, unless failing fast
+/// * artifacts found in more than one root are only handled for the first root they are found in
+/// * totals of each root are returned, with the folders skipped by ignore rules and the root they were skipped in
+pub fn build_artifacts_handler<'a, F>(
+    roots: &Roots<'a>,
     filter: &Selection,
-    platforms: &[Platform],
+    criteria: ArtifactFilter,
+    free: Option<Size>,
+    picker: Option<Age>,
     handler: F,
-) where
-    F: Fn(&BuildArtifacts, &str) -> Result<(), String>,
+) -> (Exit, Vec<Size>, Vec<(&'a str, Ignored)>)
+where
+    F: Fn(&BuildArtifacts, &str, Record) -> Result<bool, Error>,
 {
-    validate_path(&path);
-    validate_platforms_filter(filter, platforms);
+    let platforms = roots.platforms();
+
+    validate_platforms_filter(filter, &platforms);
 
-    let path = path.as_ref();
-    let path_str = path.to_string_lossy();
     let max_width = platforms
         .iter()
         .map(|p| p.name.len())
         .max()
         .unwrap_or_default();
     let mut found = 0;
+    let mut exit = Exit::Found;
+    let mut totals = vec![vec![Size::default(); platforms.len()]; roots.len()];
+    let mut ignored = Vec::new();
+    let mut found_entries = Vec::new();
 
-    for entry in BuildsWalker::new(filter, path, platforms) {
-        let offset = usize::from(!path_str.ends_with(MAIN_SEPARATOR));
-        let output = format!(
-            "[{:max_width$}] {}",
-            entry.name,
-            &entry.folder[path_str.len() + offset..]
+    for (idx, root) in roots.iter().enumerate() {
+        let configuration = roots.configuration(idx);
+        let mut walker = scan_builds(
+            root, filter, &configuration.platforms, criteria.age(), &traversal_of(configuration),
+            criteria.respect_gitignore,
         );
 
-        if let Err(err) = handler(&entry, &output) {
-            display_error_and_exit(&format!(
-                "\nException occurred while {action}ing {output}:\n  {err}"
-            ));
-        }
+        found_entries.extend(walker.by_ref()
+            .take_while(|_| !is_interrupted())
+            .filter_map(|entry| entry.map_err(report_error).ok())
+            .filter(|entry| roots.is_first_found(Path::new(&entry.folder)))
+            .map(|entry| (idx, entry)));
+        ignored.extend(walker.ignored().into_iter()
+            .filter(|ignored| roots.is_first_found(&ignored.path))
+            .map(|ignored| (root, ignored)));
+    }
+
+    let mut entries = found_entries
+        .into_par_iter()
+        .map(|(idx, mut entry)| {
+            let size = disk_usage(&entry.folder);
+
+            if (free.is_some() || picker.is_some()) && entry.activity.is_none() {
+                entry.activity = project_activity(&entry, &roots.configuration(idx).platforms);
+            }
+
+            (idx, entry, size)
+        })
+        .collect::<Vec<_>>();
+
+    // projects without files of their own have no age, they are freed last
+    if free.is_some() {
+        entries.sort_by_key(|(_, entry, _)| (entry.activity.is_none(), entry.activity));
+    }
 
+    let outputs = entries.iter()
+        .map(|(_, entry, size)| format!(
+            "[{:max_width$}] {} ({size}{})",
+            entry.name,
+            roots.display(Path::new(&entry.folder)),
+            entry.activity.map(Age::since).map_or_else(String::new, |age| format!(", {age} old"))
+        ))
+        .collect::<Vec<_>>();
+
+    let selection = picker.map(|stale| {
+        let preselected = entries.iter()
+            .enumerate()
+            .filter(|(_, (_, entry, _))| entry.activity.is_some_and(|activity| Age::since(activity) >= stale))
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+
+        pick_items("remove", outputs.clone(), &preselected)
+    });
+
+    for (position, ((idx, entry, size), output)) in entries.into_iter().zip(outputs).enumerate() {
         found += 1;
+
+        if free.is_some_and(|free| totals.iter().flatten().copied().sum::<Size>() >= free) {
+            break;
+        }
+
+        if is_interrupted() {
+            break;
+        }
+
+        if selection.as_ref().is_some_and(|selection| !selection.contains(&position)) {
+            continue;
+        }
+
+        let root = roots.iter().nth(idx).unwrap_or_default();
+
+        match handler(&entry, &output, artifact_record(&entry, size, Path::new(root))) {
+            Ok(true) => {
+                if let Some(platform) = platforms.iter().position(|p| p.name == entry.name) {
+                    totals[idx][platform] += size;
+                }
+            }
+            Ok(false) => {}
+            Err(err) => report_error(err),
+        }
     }
 
     if found == 0 {
-        println!(
+        output_text(format!(
             "No build artifacts found for {filter}{} platform{}",
             filter.choose("the ", ""),
             filter.pluralize("s")
-        );
+        ));
+
+        exit = Exit::NotFound;
+    } else if roots.is_single() {
+        display_totals(&platforms, &totals[0], max_width);
+    } else {
+        display_root_totals(roots, &platforms, &totals, max_width);
     }
+
+    (exit, totals.into_iter().map(|totals| totals.into_iter().sum()).collect(), ignored)
+}
+
+/// Describes a build artifact as a record
+fn artifact_record(artifact: &BuildArtifacts, size: Size, root: &Path) -> Record {
+    Record {
+        platform: Some(artifact.name.to_string()),
+        size: Some(size.0),
+        age: artifact.activity.map(|activity| Age::since(activity).0.as_secs()),
+        ..Record::new(RecordKind::Artifact)
+    }.with_path(&artifact.folder, root)
+}
+
+/// Displays per platform subtotals and a grand total of build artifact sizes
+fn display_totals(platforms: &[Platform], totals: &[Size], max_width: usize) {
+    const TOTAL: &str = "Total";
+
+    let max_width = max_width.max(TOTAL.len());
+
+    output_text("");
+
+    for (platform, total) in platforms.iter().zip(totals).filter(|(_, t)| t.0 > 0) {
+        output_text(format!("  {:max_width$}  {total}", platform.name));
+    }
+
+    output_text(format!("  {TOTAL:max_width$}  {}", totals.iter().copied().sum::<Size>()));
+}
+
+/// Displays per platform subtotals and a total of build artifact sizes for each root, then a grand total
+fn display_root_totals(roots: &Roots, platforms: &[Platform], totals: &[Vec<Size>], max_width: usize) {
+    const TOTAL: &str = "Total";
+
+    let max_width = max_width.max(TOTAL.len());
+
+    for (root, totals) in roots.iter().zip(totals) {
+        output_text(format!("\n  {root}"));
+
+        for (platform, total) in platforms.iter().zip(totals).filter(|(_, t)| t.0 > 0) {
+            output_text(format!("    {:max_width$}  {total}", platform.name));
+        }
+
+        output_text(format!("    {TOTAL:max_width$}  {}", totals.iter().copied().sum::<Size>()));
+    }
+
+    let width = max_width + 2;
+
+    output_text(format!("\n  {TOTAL:width$}  {}", totals.iter().flatten().copied().sum::<Size>()));
+}
+
+/// Determines the last activity of a build artifact's project, none if the project has no files of its own
+fn project_activity(artifact: &BuildArtifacts, platforms: &[Platform]) -> Option<SystemTime> {
+    last_activity(Path::new(&artifact.folder).parent()?, platforms)
 }
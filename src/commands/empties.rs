@@ -1,70 +1,221 @@
-use std::collections::HashSet;
-use std::fs::remove_dir_all;
-use std::path::{Path, PathBuf, MAIN_SEPARATOR};
+use std::fmt::Display;
+use std::fs::metadata;
+use std::iter::Sum;
+use std::path::Path;
 
-use crate::commands::walkers::EmptiesWalker;
-use crate::models::Platform;
-use crate::utils::{display_error_and_exit, get_confirmation, validate_path};
+use rayon::prelude::*;
+
+use cleaner::{
+    disk_usage, remove_and_record, scan_empties, Age, EmptyCriteria, EmptyFolder, Error, JournalEntry, Removal, Size,
+};
+
+use crate::cli::commands::empties::EmptiesFilter;
+use crate::cli::exit::Exit;
+use crate::cli::prompt::Prompt;
+use crate::cli::record::{Record, RecordKind};
+use crate::utils::{
+    get_confirmation, handle_interrupts, is_interrupted, output, output_record, output_text, pick_items,
+    report_error, traversal_of, Roots,
+};
 
 /// Lists empty folders
-pub fn list_empties<P: AsRef<Path>>(path: P, show_hidden: bool, platforms: &[Platform]) {
-    empties_handler("list", path, show_hidden, platforms, |_, msg| {
-        println!("  - {msg}");
+pub fn list_empties(paths: &[String], criteria: EmptiesFilter) -> Exit {
+    empties_handler(&Roots::new(paths), criteria, Sizes::Skip, |_, msg, record| {
+        output(format!("  - {msg}"), record);
 
-        Ok(())
-    });
+        Ok(true)
+    })
 }
 
-/// Removes empty folders
-pub fn remove_empties<P: AsRef<Path>>(
-    path: P, confirmed: bool, show_hidden: bool, platforms: &[Platform],
-) {
-    empties_handler("remove", path, show_hidden, platforms, move |empty, msg| {
-        if confirmed || get_confirmation(&msg) {
-            remove_dir_all(empty).map_err(|err| format!("{err}"))?;
+/// Removes empty folders and the junk files in them, each removal is recorded in the removal journal
+pub fn remove_empties(paths: &[String], prompt: Prompt, criteria: EmptiesFilter, removal: Removal) -> Exit {
+    handle_interrupts();
+
+    let roots = Roots::new(paths);
+    let sizes = prompt.picker().map_or(Sizes::Total, Sizes::Pick);
+    let exit = empties_handler(&roots, criteria, sizes, move |empty, msg, record| {
+        if removal == Removal::DryRun {
+            output(format!("  - {msg} - {removal}"), record.with_status(&removal));
+
+            return Ok(true);
+        }
+
+        if prompt.is_confirmed() || get_confirmation("remove", msg) {
+            let entry = JournalEntry::new(&empty.folder, None, record.size.unwrap_or_default(), !prompt.is_automatic());
 
-            if confirmed {
-                println!("  - {msg} - removed");
+            // a folder that was removed is reported as removed, even if it could not be recorded
+            match remove_and_record(entry, removal) {
+                Err(err @ Error::Journal { .. }) => report_error(err),
+                result => result?,
             }
+
+            if prompt.is_confirmed() {
+                output(format!("  - {msg} - {removal}"), record.with_status(&removal));
+            } else {
+                output_record(record.with_status(&removal));
+            }
+
+            return Ok(true);
         }
 
-        Ok(())
+        Ok(false)
     });
+
+    let exit = if is_interrupted() {
+        output_text("\nCancelled, the remaining empty folders were not removed");
+
+        Exit::Cancelled
+    } else {
+        exit
+    };
+
+    if removal == Removal::DryRun {
+        output_text("\nDry run, nothing was removed");
+    }
+
+    exit
+}
+
+/// Whether the sizes of empty folders are measured, totalled and picked from
+#[derive(Copy, Clone)]
+enum Sizes {
+    /// Empty folders are listed without sizes, each root's count is displayed for more than one root
+    Skip,
+
+    /// Sizes of empty folders are displayed and totalled
+    Total,
+
+    /// Sizes are totalled, empties are picked from a list, empties last modified before the age are preselected
+    Pick(Age),
 }
 
-/// Common empties handling logic
-fn empties_handler<F, P: AsRef<Path>>(
-    action: &str, path: P, show_hidden: bool, platforms: &[Platform], handler: F,
-)
+/// Common empties handling logic, handler returns whether an empty counts towards the total of its root
+///
+/// * each root is searched with the platforms, junk files and ignore rules of its own configuration
+/// * when picking, only empties the user picked are handled
+/// * when interrupted, searching and handling stop, totals of the empties handled so far are displayed
+/// * empties found in more than one root are only handled for the first root they are found in
+/// * failures are reported at the end, unless failing fast
+fn empties_handler<F>(roots: &Roots, criteria: EmptiesFilter, sizes: Sizes, handler: F) -> Exit
 where
-    F: Fn(&PathBuf, &str) -> Result<(), String>,
+    F: Fn(&EmptyFolder, &str, Record) -> Result<bool, Error>,
 {
-    let path = path.as_ref();
-    let path_str = path.to_string_lossy();
+    let mut found = 0;
+    let mut exit = Exit::Found;
+    let mut totals = vec![Size::default(); roots.len()];
+    let mut counts = vec![0_usize; roots.len()];
+    let mut entries = Vec::new();
 
-    validate_path(path);
+    for (idx, root) in roots.iter().enumerate() {
+        let configuration = roots.configuration(idx);
+        let empty_criteria = EmptyCriteria::new(&configuration.junk)
+            .with_hidden(criteria.hidden)
+            .with_git(criteria.respect_gitignore, criteria.include_tracked);
 
-    let mut found = 0;
-    let skipped = platforms.iter()
-        .flat_map(|p| p.folders.iter().map(String::as_str))
-        .collect::<HashSet<_>>()
-        .into_iter()
+        entries.extend(scan_empties(root, &configuration.platforms, &traversal_of(configuration), &empty_criteria)
+            .take_while(|_| !is_interrupted())
+            .filter_map(|entry| entry.map_err(report_error).ok())
+            .filter(|entry| roots.is_first_found(&entry.folder))
+            .map(|entry| (idx, entry)));
+    }
+
+    let entries = entries
+        .into_par_iter()
+        .map(|(idx, entry)| {
+            let size = matches!(sizes, Sizes::Total | Sizes::Pick(_)).then(|| disk_usage(&entry.folder));
+            let age = metadata(&entry.folder).and_then(|metadata| metadata.modified()).ok().map(Age::since);
+
+            (idx, entry, size, age)
+        })
         .collect::<Vec<_>>();
 
-    for entry in EmptiesWalker::new(path, show_hidden, &skipped) {
-        let offset = usize::from(!path_str.ends_with(MAIN_SEPARATOR));
-        let output = entry.to_string_lossy()[path_str.len() + offset..].to_string();
+    let outputs = entries.iter()
+        .map(|(_, entry, size, _)| {
+            let size = size.map(|size| format!(" ({size})")).unwrap_or_default();
+            let junk_only = if entry.junk.is_empty() { "" } else { " - empty (junk only)" };
 
-        if let Err(err) = handler(&entry, &output) {
-            display_error_and_exit(&format!(
-                "\nException occurred while {action}ing {output}:\n  {err}"
-            ));
-        }
+            format!("{}{size}{junk_only}", roots.display(&entry.folder))
+        })
+        .collect::<Vec<_>>();
+
+    let picker = if let Sizes::Pick(stale) = sizes { Some(stale) } else { None };
+    let selection = picker.map(|stale| {
+        let choices = entries.iter()
+            .zip(&outputs)
+            .map(|((_, _, _, age), output)| {
+                age.map_or_else(|| output.clone(), |age| format!("{output}, {age} old"))
+            })
+            .collect();
+        let preselected = entries.iter()
+            .enumerate()
+            .filter(|(_, (_, _, _, age))| age.is_some_and(|age| age >= stale))
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+
+        pick_items("remove", choices, &preselected)
+    });
 
+    for (position, ((idx, entry, size, _), output)) in entries.into_iter().zip(outputs).enumerate() {
         found += 1;
+
+        if is_interrupted() {
+            break;
+        }
+
+        if selection.as_ref().is_some_and(|selection| !selection.contains(&position)) {
+            continue;
+        }
+
+        let root = roots.iter().nth(idx).unwrap_or_default();
+        let record = Record {
+            size: size.map(|size| size.0),
+            junk: (!entry.junk.is_empty()).then_some(entry.junk.len()),
+            ..Record::new(RecordKind::Empty)
+        }.with_path(&entry.folder, Path::new(root));
+
+        match handler(&entry, &output, record) {
+            Ok(true) => {
+                totals[idx] += size.unwrap_or_default();
+                counts[idx] += 1;
+            }
+            Ok(false) => {}
+            Err(err) => report_error(err),
+        }
     }
 
     if found == 0 {
-        println!("No empties found at \"{path_str}\"");
+        let roots = roots.iter().map(|root| format!("\"{root}\"")).collect::<Vec<_>>().join(", ");
+
+        output_text(format!("No empties found at {roots}"));
+
+        exit = Exit::NotFound;
+    } else if matches!(sizes, Sizes::Skip) {
+        if !roots.is_single() {
+            display_root_totals(roots, &counts);
+        }
+    } else if roots.is_single() {
+        output_text(format!("\n  Total  {}", totals[0]));
+    } else {
+        display_root_totals(roots, &totals);
+    }
+
+    exit
+}
+
+/// Displays the total of empty folders for each root, their size or count, then a grand total
+fn display_root_totals<T>(roots: &Roots, totals: &[T])
+where
+    T: Copy + Display + Sum,
+{
+    const TOTAL: &str = "Total";
+
+    let max_width = roots.iter().map(str::len).max().unwrap_or_default().max(TOTAL.len());
+
+    output_text("");
+
+    for (root, total) in roots.iter().zip(totals) {
+        output_text(format!("  {root:max_width$}  {total}"));
     }
+
+    output_text(format!("  {TOTAL:max_width$}  {}", totals.iter().copied().sum::<T>()));
 }
@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+use std::path::{absolute, Path};
+
+use cleaner::{removal_history, JournalEntry, Selection, Size};
+
+use crate::cli::commands::history::{History, Period};
+use crate::cli::exit::Exit;
+use crate::cli::record::{Record, RecordKind};
+use crate::utils::{display_error_and_exit, display_failure_and_exit, output, output_text};
+
+/// Lists removals recorded in the removal journal, or totals the space they reclaimed in each period
+pub fn list_history(history: &History) -> Exit {
+    let location = history.path.as_ref().map(|path| match absolute(path) {
+        Ok(path) => path,
+        Err(err) => display_error_and_exit(&format!("Exception resolving path \"{path}\": {err}")),
+    });
+
+    let entries = match removal_history() {
+        Ok(entries) => entries,
+        Err(err) => display_failure_and_exit(&err.to_string()),
+    };
+
+    let entries = entries.into_iter()
+        .filter(|entry| location.as_ref().is_none_or(|location| entry.path.starts_with(location)))
+        .filter(|entry| entry.platform.as_ref().map_or(
+            history.types == Selection::All, |platform| history.types.matches(platform),
+        ))
+        .filter(|entry| history.within.is_none_or(|age| entry.is_within(age)))
+        .collect::<Vec<_>>();
+
+    if entries.is_empty() {
+        output_text("No removals recorded");
+
+        return Exit::NotFound;
+    }
+
+    match history.by {
+        Some(period) => display_periods(&entries, period),
+        None => display_entries(&entries, location.as_deref()),
+    }
+
+    Exit::Found
+}
+
+/// Displays each removal, oldest first, then the total space reclaimed
+fn display_entries(entries: &[JournalEntry], location: Option<&Path>) {
+    const EMPTY: &str = "empty";
+
+    let max_width = entries.iter()
+        .map(|entry| entry.platform.as_deref().map_or(EMPTY.len(), str::len))
+        .max()
+        .unwrap_or_default();
+
+    for entry in entries {
+        let platform = entry.platform.as_deref().unwrap_or(EMPTY);
+        let size = Size(entry.size);
+        let status = if entry.trashed {
+            format!("{} - moved to trash", entry.approval())
+        } else {
+            entry.approval().to_string()
+        };
+
+        let path = entry.path.to_string_lossy();
+        let record = Record {
+            platform: entry.platform.clone(),
+            path: Some(path.to_string()),
+            relative_path: location
+                .and_then(|location| entry.path.strip_prefix(location).ok())
+                .map(|relative| relative.to_string_lossy().to_string()),
+            size: Some(entry.size),
+            deleted: Some(entry.removed.clone()),
+            status: Some(status.clone()),
+            ..Record::new(RecordKind::Removed)
+        };
+
+        output(format!("  - {} [{platform:max_width$}] {path} ({size}) - {status}", entry.removed), record);
+    }
+
+    output_text(format!("\n  Total  {}", entries.iter().map(|entry| Size(entry.size)).sum::<Size>()));
+}
+
+/// Displays the space reclaimed in each period, oldest first, then the total space reclaimed
+fn display_periods(entries: &[JournalEntry], period: Period) {
+    const TOTAL: &str = "Total";
+
+    let mut periods = BTreeMap::<&str, (Size, usize)>::new();
+
+    for entry in entries {
+        let (size, count) = periods.entry(period.of(&entry.removed)).or_default();
+
+        *size += Size(entry.size);
+        *count += 1;
+    }
+
+    let max_width = periods.keys().map(|period| period.len()).max().unwrap_or_default().max(TOTAL.len());
+
+    for (period, (size, count)) in &periods {
+        let record = Record {
+            size: Some(size.0),
+            message: Some((*period).to_string()),
+            ..Record::new(RecordKind::Reclaimed)
+        };
+
+        output(format!("  {period:max_width$}  {size} ({count} folder{})", if *count == 1 { "" } else { "s" }), record);
+    }
+
+    output_text(format!("\n  {TOTAL:max_width$}  {}", entries.iter().map(|entry| Size(entry.size)).sum::<Size>()));
+}
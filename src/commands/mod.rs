@@ -1,5 +1,6 @@
 pub mod builds;
 pub mod empties;
+pub mod history;
 pub mod repos;
+pub mod restore;
 pub mod supported;
-pub mod walkers;
@@ -1,20 +1,24 @@
 #![allow(unused_qualifications)] // OnError is necessary for no on error handlers
 
-use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::path::{absolute, Path, PathBuf};
 
 use git2::string_array::StringArray;
+use cleaner::{
+    branch_name, classify_repo, has_local_branch, is_branched, is_init_only, is_main_branch,
+    scan_repos, uncommitted_changes, BranchName,
+};
 use git2::{
-    Branch, BranchType, Branches, Config, Cred, Direction, Error, ErrorCode, FetchOptions,
-    Reference, Remote, RemoteCallbacks, Repository, StatusOptions,
+    Branch, BranchType, Branches, Config, Cred, Direction, Error, ErrorCode, FetchOptions, Remote,
+    RemoteCallbacks, Repository,
 };
 use rayon::prelude::*;
-use regex::Regex;
 
 use crate::cli::commands::repos::OutdatedFilter;
-use crate::commands::walkers::ReposWalker;
-use crate::models::BranchName;
-use crate::utils::validate_path;
+use crate::cli::exit::Exit;
+use crate::cli::record::{Record, RecordKind};
+use crate::utils::{
+    buffered, is_text_output, output, output_error, output_text, report_error, traversal_of, Buffered, Roots,
+};
 
 type OnError = Option<Box<dyn Fn(Error, &'_ str) -> bool + Sync>>;
 type Message = Option<String>;
@@ -24,17 +28,20 @@ macro_rules! output_err {
     ($err: ident, $path: expr) => {
         if $err.code() != ErrorCode::UnbornBranch {
             let path = $path;
-            let err = $err.to_string().replace(&format!(" at '{path}'"), "");
+            let err = error_message(&$err, path);
 
-            println!("{path} - Err: {err}");
+            output_error(format!("{path} - Err: {err}"), &repo_record(path).with_error(err));
         }
     };
     ($err: ident, $path: expr, $msg: tt) => {
         if $err.code() != ErrorCode::UnbornBranch {
             let path = $path;
-            let err = $err.to_string().replace(&format!(" at '{path}'"), "");
+            let err = error_message(&$err, path);
 
-            println!("{} {path} - Err: {err}", $msg);
+            output_error(
+                format!("{} {path} - Err: {err}", $msg),
+                &repo_record(path).with_error(format!("{} - {err}", $msg)),
+            );
         }
     };
 }
@@ -103,118 +110,106 @@ macro_rules! ok {
     };
 }
 
-static RE_MAIN_OR_MASTER: once_cell::sync::OnceCell<regex::Regex> =
-    once_cell::sync::OnceCell::new();
-
-pub fn list_repos<P: AsRef<Path>>(path: P) {
+pub fn list_repos(paths: &[String]) -> Exit {
     repos_handler(
-        path,
+        paths,
         |_, _| (true, Message::None),
         OnError::None.as_ref(),
         || "Did not find any repos",
-    );
+    )
 }
 
-pub fn list_repos_with_detached_head<P: AsRef<Path>>(path: P) {
+pub fn list_repos_with_detached_head(paths: &[String]) -> Exit {
     repos_handler(
-        path,
+        paths,
         |repo, repo_path| (ok!(hndlr; repo.head_detached(), repo_path), Message::None),
         OnError::None.as_ref(),
         || "Did not find any detached repos",
-    );
+    )
 }
 
-pub fn list_repos_with_errors<P: AsRef<Path>>(path: P) {
+pub fn list_repos_with_errors(paths: &[String]) -> Exit {
     let handler = |err: Error, repo_path: &'_ str| {
-        output_err!(err, repo_path);
+        if err.code() != ErrorCode::UnbornBranch {
+            let message = error_message(&err, repo_path);
+
+            output(
+                format!("{repo_path} - Err: {message}"),
+                Record { message: Some(message), ..repo_record(repo_path) },
+            );
+        }
 
         true
     };
     repos_handler(
-        path,
+        paths,
         |_, _| (false, Message::None),
         Some(&handler),
         || "Did not find any repos with errors",
-    );
+    )
 }
 
-pub fn list_repos_that_are_branched<P: AsRef<Path>>(path: P) {
+pub fn list_repos_that_are_branched(paths: &[String]) -> Exit {
     repos_handler(
-        path,
-        |repo, repo_path| {
-            repo_is_branched(repo, repo_path)
-                .map_or((false, Message::None), |branched| (branched, Message::None))
-        },
+        paths,
+        |repo, repo_path| (ok!(hndlr; is_branched(repo), repo_path), Message::None),
         OnError::None.as_ref(),
         || "Did not find any repos that are in branch",
-    );
+    )
 }
 
-pub fn list_repos_that_are_init_only<P: AsRef<Path>>(path: P) {
+pub fn list_repos_that_are_init_only(paths: &[String]) -> Exit {
     repos_handler(
-        path,
-        |repo, repo_path| {
-            let init_only = repo_is_init_only(repo);
-
-            if init_only {
-                println!("{repo_path}");
-            }
-
-            (init_only, Message::None)
-        },
+        paths,
+        |repo, _| (is_init_only(repo), Message::None),
         OnError::None.as_ref(),
         || "Did not find any init only repos",
-    );
+    )
 }
 
-pub fn list_repos_with_branch<P: AsRef<Path>>(path: P, branch_name: &str) {
+pub fn list_repos_with_branch(paths: &[String], branch_name: &str) -> Exit {
     repos_handler(
-        path,
+        paths,
         |repo, repo_path| {
-            let branch = find_local_branch(repo, |name| name == branch_name);
-            let branch = ok!(hndlr; branch, repo_path);
-
-            (branch.is_some(), Message::None)
+            (ok!(hndlr; has_local_branch(repo, branch_name), repo_path), Message::None)
         },
         OnError::None.as_ref(),
         || format!("Did not find any repos with a \"{branch_name}\" branch"),
-    );
+    )
 }
 
-pub fn list_repos_with_uncommitted_changes<P: AsRef<Path>>(path: P) {
+pub fn list_repos_with_uncommitted_changes(paths: &[String]) -> Exit {
     repos_handler(
-        path,
+        paths,
         |repo, repo_path| {
-            let mut options = StatusOptions::new();
-            let mut options = options.include_untracked(true);
-            let changes = ok!(hndlr; repo.statuses(Some(&mut options)), repo_path)
-                .iter()
-                .count();
+            let changes = ok!(hndlr; uncommitted_changes(repo), repo_path);
 
             (changes > 0, Some(format!("changes: {changes}")))
         },
         OnError::None.as_ref(),
         || "Did not find any repos with uncommitted changes",
-    );
+    )
 }
 
-pub fn list_repos_without_configured_remotes<P: AsRef<Path>>(path: P) {
+pub fn list_repos_without_configured_remotes(paths: &[String]) -> Exit {
     repos_handler(
-        path,
+        paths,
         |repo, repo_path| {
             let remotes = ok!(hndlr; repo.remotes(), repo_path);
-            let has_remotes = remotes.is_empty() && !repo_is_init_only(repo);
+            let has_remotes = remotes.is_empty() && !is_init_only(repo);
 
             (has_remotes, Message::None)
         },
         OnError::None.as_ref(),
         || "Did not find any repos with out remotes",
-    );
+    )
 }
 
-pub fn list_outdated_repos<P: AsRef<Path>>(path: P, filter: OutdatedFilter, only_main: bool) {
+pub fn list_outdated_repos(
+    paths: &[String], filter: OutdatedFilter, only_main: bool,
+) -> Exit {
     repos_handler(
-        path,
+        paths,
         |repo, repo_path| {
             ok!(hndlr; fetch_all_remotes(repo, repo_path), repo_path);
 
@@ -233,12 +228,12 @@ pub fn list_outdated_repos<P: AsRef<Path>>(path: P, filter: OutdatedFilter, only
         },
         OnError::None.as_ref(),
         || "Did not find any outdated repos",
-    );
+    )
 }
 
-pub fn list_up_to_date_repos<P: AsRef<Path>>(path: P, only_main: bool) {
+pub fn list_up_to_date_repos(paths: &[String], only_main: bool) -> Exit {
     repos_handler(
-        path,
+        paths,
         |repo, repo_path| {
             ok!(hndlr; fetch_all_remotes(repo, repo_path), repo_path);
 
@@ -254,7 +249,21 @@ pub fn list_up_to_date_repos<P: AsRef<Path>>(path: P, only_main: bool) {
         },
         OnError::None.as_ref(),
         || "Did not find any up-to-date repos",
-    );
+    )
+}
+
+/// Adds uncommitted changes and ahead & behind commits, of the current branch, to a repo record
+fn describe_repo(repo: &Repository, record: &mut Record) {
+    let state = classify_repo(repo);
+
+    record.changes = state.changes;
+    record.ahead = state.ahead;
+    record.behind = state.behind;
+}
+
+/// Cleans up a git error message, removing the repo path which is already part of the output
+fn error_message(err: &Error, path: &str) -> String {
+    err.to_string().replace(&format!(" at '{path}'"), "")
 }
 
 fn fetch_all_remotes(repo: &Repository, repo_path: &str) -> Result<(), Error> {
@@ -320,58 +329,21 @@ fn credentials<'a>() -> RemoteCallbacks<'a> {
 }
 
 fn filter_main_branches(
-    branches: Branches,
+    branches: Branches<'_>,
     only_main: bool,
 ) -> impl Iterator<Item = Result<(Branch<'_>, BranchType), Error>> {
     branches.filter(move |branch| {
         if only_main && !branch.is_err() {
-            let main_branch = re_main_or_master();
-
             // just checked branch is not err
             let (branch, _) = branch.as_ref().unwrap();
 
-            let branch_name = branch.name().unwrap_or_default().unwrap_or_default();
-
-            main_branch.is_match(branch_name)
+            is_main_branch(branch.name().unwrap_or_default().unwrap_or_default())
         } else {
             true
         }
     })
 }
 
-fn find_local_branch<'a, P>(repo: &'a Repository, predicate: P) -> Result<Option<Branch<'a>>, Error>
-where
-    P: Fn(&'_ str) -> bool + 'a + Copy,
-{
-    let mut branches = repo.branches(Some(BranchType::Local))?;
-
-    let branch = branches.find(|branch| {
-        let Ok((branch, _)) = branch else {
-            return false;
-        };
-
-        branch.name().unwrap_or_default().is_some_and(predicate)
-    });
-
-    match branch {
-        None => Ok(None),
-        Some(Ok((branch, _))) => Ok(Some(branch)),
-        Some(Err(err)) => Err(err),
-    }
-}
-
-fn get_branch_name<'a>(reference: &'a Reference) -> BranchName<'a> {
-    let name = reference.shorthand().unwrap_or_default();
-
-    if name == "HEAD" {
-        if let Some(oid) = reference.target() {
-            return BranchName::Head(oid);
-        }
-    }
-
-    BranchName::Branch(name)
-}
-
 fn get_config() -> Result<Config, Error> {
     if let Ok(config) = Config::open_default() {
         Ok(config)
@@ -388,75 +360,121 @@ fn get_config() -> Result<Config, Error> {
     }
 }
 
-fn re_main_or_master() -> &'static Regex {
-    RE_MAIN_OR_MASTER.get_or_init(|| Regex::new("^(refs/heads/)?(main|master)$").unwrap())
-}
-
-fn repos_handler<P, S, H, OE, E, NF>(path: P, handler: H, on_error: Option<&OE>, not_found: NF)
+fn repos_handler<S, H, OE, E, NF>(
+    paths: &[String], handler: H, on_error: Option<&OE>, not_found: NF,
+) -> Exit
 where
-    P: AsRef<Path>,
     S: AsRef<str>,
     H: Fn(&'_ Repository, &'_ str) -> (bool, Option<S>) + Sync,
     OE: Fn(Error, &'_ str) -> bool + Sync,
     E: AsRef<str>,
     NF: Fn() -> E,
 {
-    validate_path(&path);
+    let mut found = false;
 
-    let found = AtomicBool::new(false);
+    for (found_repo, output) in check_repos(paths, handler, on_error) {
+        found |= found_repo;
 
-    ReposWalker::new(&path)
-        .par_bridge()
-        .for_each(|(repo, repo_path)| {
-            let repo_path = repo_path.to_string_lossy();
+        output.flush();
+    }
 
-            let repo = match repo {
-                Ok(repo) => repo,
-                Err(err) => {
-                    if let Some(on_error) = on_error {
-                        if on_error(err, &repo_path) {
-                            found.store(true, Ordering::Relaxed);
-                        }
-                    }
+    if !found {
+        output_text(not_found().as_ref());
+    }
 
-                    return;
-                }
-            };
+    Exit::found(found)
+}
 
-            let (found_repo, message) = handler(&repo, &repo_path);
+/// Checks repos in parallel, returns if each repo was found along with its output, in traversal order
+///
+/// * output is held back while repos are checked, results are listed in the same order regardless of the number of
+///   threads
+pub fn check_repos<S, H, OE>(paths: &[String], handler: H, on_error: Option<&OE>) -> Vec<(bool, Buffered)>
+where
+    S: AsRef<str>,
+    H: Fn(&'_ Repository, &'_ str) -> (bool, Option<S>) + Sync,
+    OE: Fn(Error, &'_ str) -> bool + Sync,
+{
+    let roots = Roots::new(paths);
+    let repos = roots.iter().enumerate()
+        .flat_map(|(idx, root)| {
+            scan_repos(root, &traversal_of(roots.configuration(idx))).map(move |entry| (root, entry))
+        })
+        .filter_map(|(root, entry)| entry.map_err(report_error).ok().map(|entry| (root, entry)))
+        .filter(|(_, (_, repo_path))| roots.is_first_found(repo_path))
+        .collect::<Vec<_>>();
+
+    repos.into_par_iter()
+        .map(|(root, (repo, repo_path))| buffered(|| check_repo(root, repo, &repo_path, &handler, on_error)))
+        .collect()
+}
 
-            if found_repo {
-                found.store(true, Ordering::Relaxed);
+/// Checks a repo with a handler, outputs it if found, returns if it was found
+fn check_repo<S, H, OE>(
+    root: &str, repo: Result<Repository, Error>, repo_path: &Path, handler: &H, on_error: Option<&OE>,
+) -> bool
+where
+    S: AsRef<str>,
+    H: Fn(&'_ Repository, &'_ str) -> (bool, Option<S>),
+    OE: Fn(Error, &'_ str) -> bool,
+{
+    let repo_path = repo_path.to_string_lossy();
 
-                let repo_head = &ok!(bail; repo.head(), repo_path);
-                let branch_name = get_branch_name(repo_head);
+    let repo = match repo {
+        Ok(repo) => repo,
+        Err(err) => return on_error.is_some_and(|on_error| on_error(err, &repo_path)),
+    };
 
-                let message =
-                    message.map_or_else(String::new, |message| format!("; {}", message.as_ref()));
+    let (found_repo, message) = handler(&repo, &repo_path);
 
-                println!("{repo_path} - {branch_name}{message}");
-            }
-        });
+    if !found_repo {
+        return false;
+    }
+
+    let message = message.map_or_else(String::new, |message| format!("; {}", message.as_ref()));
+
+    // unborn repos have no head, they are listed without a branch
+    let repo_head = match repo.head() {
+        Ok(head) => Some(head),
+        Err(err) if err.code() == ErrorCode::UnbornBranch => None,
+        Err(err) => {
+            output_err!(err, &repo_path);
+
+            return true;
+        }
+    };
+
+    let mut record = repo_record(&repo_path);
+
+    record.relative_path = Path::new(&*repo_path).strip_prefix(root).ok()
+        .map(|relative| relative.to_string_lossy().to_string());
 
-    if !found.load(Ordering::Relaxed) {
-        println!("{}", not_found().as_ref());
+    if !is_text_output() {
+        describe_repo(&repo, &mut record);
     }
-}
 
-fn repo_is_branched(repo: &Repository, repo_path: &str) -> Option<bool> {
-    let main_branch = re_main_or_master();
+    let Some(repo_head) = repo_head else {
+        output(format!("{repo_path}{message}"), record);
 
-    Some(
-        ok!(opt; repo.head(), repo_path)
-            .name()
-            .is_some_and(|head_name| !main_branch.is_match(head_name)),
-    )
+        return true;
+    };
+
+    let branch_name = branch_name(&repo_head);
+
+    record.branch = Some(match branch_name {
+        BranchName::Branch(name) => name.to_string(),
+        BranchName::Head(oid) => oid.to_string(),
+    });
+
+    output(format!("{repo_path} - {branch_name}{message}"), record);
+
+    true
 }
 
-fn repo_is_init_only(repo: &Repository) -> bool {
-    if let Err(err) = repo.head() {
-        err.code() == ErrorCode::UnbornBranch
-    } else {
-        false
+/// Initializes a record describing a repo
+fn repo_record(path: &str) -> Record {
+    Record {
+        path: Some(absolute(path).map_or_else(|_| path.to_string(), |p| p.to_string_lossy().to_string())),
+        ..Record::new(RecordKind::Repo)
     }
 }
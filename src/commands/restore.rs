@@ -0,0 +1,97 @@
+use std::path::{absolute, Path};
+
+use cleaner::{restore_folder, trashed_folders, Error, TrashedItem};
+
+use crate::cli::exit::Exit;
+use crate::cli::record::{Record, RecordKind};
+use crate::utils::{
+    display_error_and_exit, display_failure_and_exit, get_confirmation, handle_interrupts, is_interrupted, output,
+    output_record, output_text, report_error,
+};
+
+/// Lists folders cleaner moved to the trash
+pub fn list_trashed<P: AsRef<Path>>(path: P) -> Exit {
+    trashed_handler(path, |_, msg, record| {
+        output(format!("  - {msg}"), record);
+
+        Ok(())
+    })
+}
+
+/// Restores folders cleaner moved to the trash
+pub fn restore_trashed<P: AsRef<Path>>(path: P, confirmed: bool) -> Exit {
+    handle_interrupts();
+
+    let exit = trashed_handler(path, move |item, msg, record| {
+        if confirmed || get_confirmation("restore", msg) {
+            restore_folder(item)?;
+
+            if confirmed {
+                output(format!("  - {msg} - restored"), record.with_status(&"restored"));
+            } else {
+                output_record(record.with_status(&"restored"));
+            }
+        }
+
+        Ok(())
+    });
+
+    if is_interrupted() {
+        output_text("\nCancelled, the remaining folders were not restored");
+
+        return Exit::Cancelled;
+    }
+
+    exit
+}
+
+/// Common trashed folders handling logic
+///
+/// * failures are reported at the end, unless failing fast
+/// * when interrupted, handling stops after the folder being handled
+fn trashed_handler<F, P: AsRef<Path>>(path: P, handler: F) -> Exit
+where
+    F: Fn(&TrashedItem, &str, Record) -> Result<(), Error>,
+{
+    let path = match absolute(&path) {
+        Ok(path) => path,
+        Err(err) => display_error_and_exit(&format!(
+            "Exception resolving path \"{}\": {err}", path.as_ref().to_string_lossy()
+        )),
+    };
+
+    let items = match trashed_folders() {
+        Ok(items) => items,
+        Err(err) => display_failure_and_exit(&err.to_string()),
+    };
+
+    let mut found = 0;
+    let mut exit = Exit::Found;
+
+    for item in items.iter().filter(|item| item.original.starts_with(&path)) {
+        if is_interrupted() {
+            break;
+        }
+
+        let output = format!("{} (trashed {})", item.original.to_string_lossy(), item.deleted);
+
+        let record = Record {
+            deleted: Some(item.deleted.clone()),
+            ..Record::new(RecordKind::Trashed)
+        }.with_path(&item.original, &path);
+
+        if let Err(err) = handler(item, &output, record) {
+            report_error(err);
+        }
+
+        found += 1;
+    }
+
+    if found == 0 {
+        output_text(format!("No trashed folders found for \"{}\"", path.to_string_lossy()));
+
+        exit = Exit::NotFound;
+    }
+
+    exit
+}
@@ -1,20 +1,21 @@
 use std::collections::{HashMap, HashSet};
-use std::fs::{remove_file, OpenOptions};
-use std::io::BufWriter;
+use std::fs::{create_dir_all, remove_file};
 use std::sync::Once;
 
+use cleaner::{write_configuration, Configuration, Filter, LayeredConfiguration, Platform};
 use inquire::validator::StringValidator;
 use inquire::{Confirm, MultiSelect, Select, Text};
 
-use crate::models::Filter;
+use crate::cli::exit::Exit;
+use crate::cli::record::{Record, RecordKind};
 use crate::utils::{
-    display_error_and_exit, list_output, path_of_supported_platforms_configuration,
+    configuration_location, display_failure_and_exit, is_text_output, list_output, load_base_layers,
+    load_user_layers, output, output_record, path_of_supported_platforms_configuration, read_user_configuration,
     validate_platform, validate_unique_values,
 };
-use crate::{Platform, PLATFORMS};
 
 /// Manage supported platforms configuration
-pub fn manage_configuration() {
+pub fn manage_configuration() -> Exit {
     const ADD: &str = "Add";
     const MODIFY: &str = "Modify";
     const DELETE: &str = "Delete";
@@ -22,7 +23,7 @@ pub fn manage_configuration() {
     const CANCEL: &str = "Cancel Modifications";
     const LIST: &str = "List";
 
-    let mut platforms = PLATFORMS.clone();
+    let mut platforms = load_user_layers().configuration.platforms;
     let mut modified = false;
 
     loop {
@@ -57,18 +58,20 @@ pub fn manage_configuration() {
             Ok(_) => unreachable!(),
         }
     }
+
+    Exit::Found
 }
 
 /// Deletes platform configuration file to reset configuration to default
-pub fn reset_configuration(confirmed: bool) {
+pub fn reset_configuration(confirmed: bool) -> Exit {
     if !path_of_supported_platforms_configuration().exists() {
         println!("Configuration of supported platforms is reset");
-        return;
+        return Exit::Found;
     }
 
     if confirmed {
         reset_configuration_json();
-        return;
+        return Exit::Found;
     }
 
     if super::supported::confirmed(
@@ -76,11 +79,15 @@ pub fn reset_configuration(confirmed: bool) {
     ) {
         println!();
         reset_configuration_json();
+
+        return Exit::Found;
     }
+
+    Exit::Cancelled
 }
 
 /// Lists supported platform configuration
-pub fn supported_platforms(platforms: &[Platform]) {
+pub fn supported_platforms(platforms: &[Platform]) -> Exit {
     let mut separator = false;
     let skip_first = Once::new();
     let dupes = platforms
@@ -125,6 +132,17 @@ pub fn supported_platforms(platforms: &[Platform]) {
     };
 
     for platform in platforms {
+        if !is_text_output() {
+            output_record(Record {
+                platform: Some(platform.name.clone()),
+                folders: Some(platform.folders.clone()),
+                associated: Some(platform.associated.iter().map(|f| f.as_ref().to_string()).collect()),
+                ..Record::new(RecordKind::Platform)
+            });
+
+            continue;
+        }
+
         if separator {
             println!();
         }
@@ -133,14 +151,44 @@ pub fn supported_platforms(platforms: &[Platform]) {
 
         display_platform(platform, check_name, check_builds, check_associated);
     }
+
+    Exit::found(!platforms.is_empty())
 }
 
-/// Shows the path of the configuration json file
-pub fn show_configuration() {
-    println!(
-        "{}",
-        path_of_supported_platforms_configuration().to_string_lossy()
+/// Lists the configuration layer each supported platform came from
+pub fn supported_platform_origins(layered: &LayeredConfiguration) -> Exit {
+    let max_width = layered.configuration.platforms.iter().map(|p| p.name.len()).max().unwrap_or_default();
+
+    for (platform, layer) in layered.origins() {
+        output(
+            format!("  {:max_width$}  {layer}", platform.name),
+            Record {
+                platform: Some(platform.name.clone()),
+                message: Some(layer.to_string()),
+                ..Record::new(RecordKind::Platform)
+            },
+        );
+    }
+
+    Exit::found(!layered.configuration.platforms.is_empty())
+}
+
+/// Shows the path of the configuration json file, and why its location was chosen
+pub fn show_configuration() -> Exit {
+    let location = configuration_location();
+    let path = location.path.to_string_lossy();
+    let reason = location.source.to_string();
+
+    output(
+        format!("{path}\n  {reason}"),
+        Record {
+            path: Some(path.to_string()),
+            message: Some(reason),
+            ..Record::new(RecordKind::Configuration)
+        },
     );
+
+    Exit::Found
 }
 
 /// Lets user interactively define and add a new platform to configuration
@@ -170,6 +218,7 @@ fn add_new_platform(platforms: &mut Vec<Platform>) -> bool {
         name,
         folders,
         associated,
+        disabled: false,
     };
 
     if let Some(equivalent) = platforms.iter().find(|p| p.same_as(&platform)) {
@@ -485,27 +534,42 @@ fn reset_configuration_json() {
         match remove_file(&path) {
             Ok(()) => println!("Configuration of supported platforms has been reset"),
             Err(err) => {
-                display_error_and_exit(&format!("Exception resenting configuration: {err}"))
+                display_failure_and_exit(&format!("Exception resetting configuration: {err}"))
             }
         }
     }
 }
 
-/// Saves platform configuration
-fn save_platforms(platforms: &[Platform]) -> bool {
-    let file = OpenOptions::new()
-        .write(true)
-        .truncate(true)
-        .open(path_of_supported_platforms_configuration());
-
-    let Ok(file) = file else {
-        return false;
+/// Saves platform configuration as the user configuration layer
+///
+/// * only platforms added, or changed from the built-in defaults and system configuration, are saved
+/// * platforms of the built-in defaults and system configuration that were deleted are saved as disabled
+pub fn save_platforms(platforms: &[Platform]) -> bool {
+    let user = read_user_configuration().unwrap_or_default();
+    let base = load_base_layers().configuration.platforms;
+    let changed = platforms.iter()
+        .filter(|platform| !base.iter().any(|existing| existing == *platform && existing.same_as(platform)))
+        .cloned();
+    let deleted = base.iter()
+        .filter(|platform| !platforms.contains(platform))
+        .map(|platform| Platform::disable(&platform.name));
+
+    let configuration = Configuration {
+        platforms: changed.chain(deleted).collect(),
+        ignore: user.ignore,
+        junk: user.junk,
     };
 
-    let writer = BufWriter::new(file);
+    let path = path_of_supported_platforms_configuration();
+
+    if let Some(parent) = path.parent()
+        && let Err(err) = create_dir_all(parent) {
+        println!("\nException creating configuration folder: {err}\n");
+        return false;
+    }
 
-    if let Err(err) = serde_json::to_writer(writer, platforms) {
-        println!("\nException saving configuration: {err}\n");
+    if let Err(err) = write_configuration(path, &configuration) {
+        println!("\nException saving {err}\n");
         false
     } else {
         true
@@ -0,0 +1,124 @@
+use std::ffi::OsString;
+use std::fs::{copy, read_to_string, write};
+use std::io;
+use std::path::{absolute, Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::error::Error;
+use crate::migrations::{migrate, version_of, CONFIGURATION_VERSION};
+use crate::models::{Configuration, ConfigurationFormat};
+
+/// Default configuration of supported platforms
+pub const DEFAULT_PLATFORMS: &str = include_str!("../supported-platforms.json");
+
+/// Name of project configuration files, layered over the user configuration when scanning a path in the project
+pub const PROJECT_CONFIGURATION: &str = ".cleaner.json";
+
+/// Extensions of configuration files, in order of precedence when a folder has more than one configuration
+pub const CONFIGURATION_EXTENSIONS: [&str; 4] = ["json", "toml", "yaml", "yml"];
+
+/// Default junk files, OS and editor litter that does not keep a folder from being empty
+pub const DEFAULT_JUNK: [&str; 4] = [".DS_Store", "Thumbs.db", "desktop.ini", "*.swp"];
+
+/// Reads a configuration of supported platforms, ignore rules and junk files
+///
+/// * JSON, TOML or YAML, chosen by the extension of the configuration file
+///
+/// # Errors
+///
+/// * configuration file can not be read or is not a valid configuration
+pub fn read_configuration<P: AsRef<Path>>(path: P) -> Result<Configuration, Error> {
+    read_document(path.as_ref())
+}
+
+/// Writes a configuration of supported platforms, ignore rules and junk files, replacing any existing configuration
+///
+/// * JSON, TOML or YAML, chosen by the extension of the configuration file
+/// * an existing configuration is backed up first, to a `.bak` file next to it
+///
+/// # Errors
+///
+/// * configuration file can not be backed up or written
+pub fn write_configuration<P: AsRef<Path>>(path: P, configuration: &Configuration) -> Result<(), Error> {
+    let path = path.as_ref();
+
+    if path.exists() {
+        backup_configuration(path, "bak").map_err(|err| Error::config(path, err))?;
+    }
+
+    write_document(path, configuration)
+}
+
+/// Migrates a configuration file of an earlier version to the current version, in place
+///
+/// * the configuration is backed up first, to a `.v{version}.bak` file next to it
+/// * returns the backup of a migrated configuration, none if it is already current
+///
+/// # Errors
+///
+/// * configuration file can not be read, is not a valid configuration, is newer than this version of cleaner,
+///   or can not be backed up or written
+pub fn migrate_configuration<P: AsRef<Path>>(path: P) -> Result<Option<PathBuf>, Error> {
+    let path = path.as_ref();
+    let document: Value = read_document(path)?;
+    let version = version_of(&document).map_err(|err| Error::config(path, err))?;
+
+    if version == CONFIGURATION_VERSION {
+        return Ok(None);
+    }
+
+    let (document, _) = migrate(document).map_err(|err| Error::config(path, err))?;
+    let configuration = Configuration::deserialize(document).map_err(|err| Error::config(path, err))?;
+    let backup = backup_configuration(path, &format!("v{version}.bak")).map_err(|err| Error::config(path, err))?;
+
+    write_document(path, &configuration).map(|()| Some(backup))
+}
+
+/// Finds the project configuration closest to a scanned path, in the path itself or a folder above it
+///
+/// * `.cleaner.toml`, `.cleaner.yaml` or `.cleaner.yml` are found as well, see [`find_configuration`]
+#[must_use]
+pub fn find_project_configuration<P: AsRef<Path>>(path: P) -> Option<PathBuf> {
+    let path = absolute(path).ok()?;
+
+    path.ancestors().find_map(|folder| find_configuration(folder.join(PROJECT_CONFIGURATION)))
+}
+
+/// Finds an existing configuration file, by trying each of the configuration extensions on a file name in order
+///
+/// * i.e. `supported-platforms.toml` for `supported-platforms.json` if only the former exists
+#[must_use]
+pub fn find_configuration<P: AsRef<Path>>(path: P) -> Option<PathBuf> {
+    CONFIGURATION_EXTENSIONS.iter()
+        .map(|extension| path.as_ref().with_extension(extension))
+        .find(|configuration| configuration.is_file())
+}
+
+/// Copies a configuration file to a backup next to it, named with an additional extension
+fn backup_configuration(path: &Path, extension: &str) -> io::Result<PathBuf> {
+    let mut name = path.file_name().map(OsString::from).unwrap_or_default();
+
+    name.push(".");
+    name.push(extension);
+
+    let backup = path.with_file_name(name);
+
+    copy(path, &backup).map(|_| backup)
+}
+
+/// Reads a configuration file in the format of its extension
+fn read_document<T: DeserializeOwned>(path: &Path) -> Result<T, Error> {
+    let document = read_to_string(path).map_err(|err| Error::config(path, err))?;
+
+    ConfigurationFormat::of(path).parse(&document).map_err(|err| Error::config(path, err))
+}
+
+/// Writes a configuration file in the format of its extension, replacing any existing file
+fn write_document(path: &Path, configuration: &Configuration) -> Result<(), Error> {
+    let document = ConfigurationFormat::of(path).format(configuration).map_err(|err| Error::config(path, err))?;
+
+    write(path, document).map_err(|err| Error::config(path, err))
+}
@@ -0,0 +1,165 @@
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Errors that can occur while searching for and cleaning up folders
+#[derive(Debug)]
+pub enum Error {
+    /// Failure reading or writing a configuration of supported platforms
+    Config {
+        /// Configuration file
+        path: PathBuf,
+
+        /// Cause of failure
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+
+    /// Invalid exclude pattern
+    Exclude {
+        /// Pattern that could not be parsed
+        pattern: String,
+
+        /// Cause of failure
+        source: globset::Error,
+    },
+
+    /// Failure checking a folder against its enclosing git repository
+    Git {
+        /// Folder that could not be checked
+        path: PathBuf,
+
+        /// Cause of failure
+        source: git2::Error,
+    },
+
+    /// Invalid ignore rule
+    Ignore {
+        /// `.cleanerignore` file of the rule, none if the rule is from the configuration
+        path: Option<PathBuf>,
+
+        /// Cause of failure
+        source: ignore::Error,
+    },
+
+    /// Symbolic link to a folder that contains it, not followed
+    Loop {
+        /// Symbolic link that was not followed
+        path: PathBuf,
+
+        /// Folder the link points back to
+        ancestor: PathBuf,
+    },
+
+    /// Failure removing a folder
+    Remove {
+        /// Folder that could not be removed
+        path: PathBuf,
+
+        /// Cause of failure
+        source: io::Error,
+    },
+
+    /// Failure restoring a folder from the trash
+    Restore {
+        /// Original location of the folder that could not be restored
+        path: PathBuf,
+
+        /// Cause of failure
+        source: io::Error,
+    },
+
+    /// Failure accessing the trash
+    Trash {
+        /// Cause of failure
+        source: io::Error,
+    },
+
+    /// Failure reading or writing the removal journal
+    Journal {
+        /// Cause of failure
+        source: io::Error,
+    },
+
+    /// Failure reading a folder while searching
+    Walk {
+        /// Folder that could not be read
+        path: PathBuf,
+
+        /// Cause of failure
+        source: io::Error,
+    },
+}
+
+impl Error {
+    /// Initializes a configuration error
+    pub fn config<P, E>(path: P, source: E) -> Self
+    where
+        P: AsRef<Path>,
+        E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        Self::Config { path: path.as_ref().to_path_buf(), source: source.into() }
+    }
+
+    /// Initializes a walk error, falls back to searched path if error has no path
+    pub fn walk<P: AsRef<Path>>(source: walkdir::Error, searched: P) -> Self {
+        let path = source.path().unwrap_or_else(|| searched.as_ref()).to_path_buf();
+
+        Self::Walk { path, source: source.into() }
+    }
+
+    /// Path the error occurred on, if any
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            Self::Config { path, .. } | Self::Git { path, .. } | Self::Loop { path, .. } |
+            Self::Remove { path, .. } | Self::Restore { path, .. } | Self::Walk { path, .. } => Some(path),
+            Self::Ignore { path, .. } => path.as_deref(),
+            Self::Exclude { .. } | Self::Journal { .. } | Self::Trash { .. } => None,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Config { path, source } =>
+                fmt.write_fmt(format_args!("configuration \"{}\": {source}", path.to_string_lossy())),
+            Self::Exclude { pattern, source } =>
+                fmt.write_fmt(format_args!("invalid exclude pattern \"{pattern}\": {}", source.kind())),
+            Self::Git { path, source } =>
+                fmt.write_fmt(format_args!("failed to check \"{}\" with git: {}", path.to_string_lossy(), source.message())),
+            Self::Ignore { path: Some(path), source } =>
+                fmt.write_fmt(format_args!("invalid ignore rule in \"{}\": {source}", path.to_string_lossy())),
+            Self::Ignore { path: None, source } =>
+                fmt.write_fmt(format_args!("invalid ignore rule in configuration: {source}")),
+            Self::Journal { source } =>
+                fmt.write_fmt(format_args!("failed to access the removal journal: {source}")),
+            Self::Loop { path, ancestor } => fmt.write_fmt(format_args!(
+                "symbolic link \"{}\" loops back to \"{}\", not followed",
+                path.to_string_lossy(), ancestor.to_string_lossy(),
+            )),
+            Self::Remove { path, source } =>
+                fmt.write_fmt(format_args!("failed to remove \"{}\": {source}", path.to_string_lossy())),
+            Self::Restore { path, source } =>
+                fmt.write_fmt(format_args!("failed to restore \"{}\": {source}", path.to_string_lossy())),
+            Self::Trash { source } =>
+                fmt.write_fmt(format_args!("failed to access the trash: {source}")),
+            Self::Walk { path, source } =>
+                fmt.write_fmt(format_args!("failed to search \"{}\": {source}", path.to_string_lossy())),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Config { source, .. } => Some(source.as_ref()),
+            Self::Exclude { source, .. } => Some(source),
+            Self::Git { source, .. } => Some(source),
+            Self::Ignore { source, .. } => Some(source),
+            Self::Loop { .. } => None,
+            Self::Journal { source } | Self::Remove { source, .. } | Self::Restore { source, .. } |
+            Self::Trash { source } | Self::Walk { source, .. } => Some(source),
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashSet;
+use std::fs::{read_dir, Metadata};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use rayon::prelude::*;
+use walkdir::WalkDir;
+
+use crate::models::{Platform, Size};
+
+/// Identifies a file by device and inode, used to count hard links only once
+type FileId = (u64, u64);
+
+/// Folder of a git repository's history
+const GIT_FOLDER: &str = ".git";
+
+/// Calculates the recursive on disk size of a folder, hard linked files are counted once
+///
+/// * a symbolic link to a folder only accounts for itself, removing it does not free its folder
+pub fn disk_usage<P: AsRef<Path>>(path: P) -> Size {
+    let path = path.as_ref();
+    let seen = Mutex::new(HashSet::new());
+    let own = path.symlink_metadata().map_or(0, |metadata| allocated(&metadata));
+
+    if path.is_symlink() {
+        return Size(own);
+    }
+
+    Size(own + folder_usage(path, &seen))
+}
+
+/// Determines the last time a file in a project was modified, none if it has no files of its own
+///
+/// * `.git` and the build artifact folders of all platforms are excluded, at any depth, they are not project activity
+pub fn last_activity<P: AsRef<Path>>(project: P, platforms: &[Platform]) -> Option<SystemTime> {
+    let excluded = |name: &str| name == GIT_FOLDER || platforms.iter()
+        .flat_map(|platform| &platform.folders)
+        .any(|artifact| name.eq_ignore_ascii_case(artifact));
+
+    WalkDir::new(project)
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0 || !entry.file_type().is_dir() || !excluded(&entry.file_name().to_string_lossy())
+        })
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok()?.modified().ok())
+        .max()
+}
+
+/// Recursively sums the allocated size of a folder's entries in parallel
+fn folder_usage(path: &Path, seen: &Mutex<HashSet<FileId>>) -> u64 {
+    let Ok(entries) = read_dir(path) else {
+        return 0;
+    };
+
+    entries
+        .par_bridge()
+        .filter_map(Result::ok)
+        .map(|entry| {
+            // does not follow symlinks, a link only accounts for itself
+            let Ok(metadata) = entry.metadata() else {
+                return 0;
+            };
+
+            if metadata.is_dir() {
+                allocated(&metadata) + folder_usage(&entry.path(), seen)
+            } else if first_link(&metadata, seen) {
+                allocated(&metadata)
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+/// Allocated size of an entry on disk
+#[cfg(unix)]
+fn allocated(metadata: &Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+
+    metadata.blocks() * 512
+}
+
+/// Allocated size of an entry on disk
+#[cfg(not(unix))]
+fn allocated(metadata: &Metadata) -> u64 {
+    metadata.len()
+}
+
+/// Checks if a file has not already been counted through another hard link
+#[cfg(unix)]
+fn first_link(metadata: &Metadata, seen: &Mutex<HashSet<FileId>>) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    if metadata.nlink() < 2 {
+        return true;
+    }
+
+    seen.lock()
+        .map_or(true, |mut seen| seen.insert((metadata.dev(), metadata.ino())))
+}
+
+/// Checks if a file has not already been counted through another hard link
+#[cfg(not(unix))]
+const fn first_link(_: &Metadata, _: &Mutex<HashSet<FileId>>) -> bool {
+    true
+}
@@ -0,0 +1,41 @@
+use std::fs::{create_dir_all, read_to_string, OpenOptions};
+use std::io::{self, ErrorKind, Write};
+use std::path::PathBuf;
+
+use crate::fs::xdg::data_home;
+use crate::models::JournalEntry;
+
+/// Appends an entry to the removal journal, creating the journal if needed
+pub fn append_to_journal(entry: &JournalEntry) -> io::Result<()> {
+    let journal = journal_file()?;
+
+    if let Some(parent) = journal.parent() {
+        create_dir_all(parent)?;
+    }
+
+    let mut line = serde_json::to_string(entry)?;
+
+    line.push('\n');
+
+    // a single write of a whole line, so concurrent cleaners do not interleave entries
+    OpenOptions::new().create(true).append(true).open(journal)?.write_all(line.as_bytes())
+}
+
+/// Reads all entries of the removal journal, oldest first, lines that can not be parsed are skipped
+pub fn journal_entries() -> io::Result<Vec<JournalEntry>> {
+    let journal = match read_to_string(journal_file()?) {
+        Ok(journal) => journal,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => return Err(err),
+    };
+
+    Ok(journal.lines().filter_map(|line| serde_json::from_str(line).ok()).collect())
+}
+
+/// Gets the location of the removal journal, in the user's data folder
+fn journal_file() -> io::Result<PathBuf> {
+    Ok(data_home()
+        .ok_or_else(|| io::Error::new(ErrorKind::NotFound, "could not determine journal location"))?
+        .join("cleaner")
+        .join("history.ndjson"))
+}
@@ -0,0 +1,10 @@
+pub use disk::{disk_usage, last_activity};
+pub use journal::{append_to_journal, journal_entries};
+pub use trash::{move_to_trash, restore_from_trash, trashed_items};
+#[cfg(test)]
+pub use trash::copy_folder;
+
+mod disk;
+mod journal;
+mod trash;
+mod xdg;
@@ -0,0 +1,342 @@
+use std::collections::HashMap;
+use std::fs::{
+    copy, create_dir, create_dir_all, read_dir, read_link, read_to_string, remove_dir_all, remove_file, rename, File,
+    FileTimes, Metadata, OpenOptions,
+};
+use std::io::{self, ErrorKind, Write};
+use std::path::{absolute, Path, PathBuf};
+
+use chrono::Local;
+
+use crate::fs::xdg::data_home;
+use crate::models::TrashedItem;
+
+/// Moves a folder to the trash, recording it as trashed by cleaner
+///
+/// * folders on the file system of the user's home trash are moved to the home trash
+/// * folders on other file systems are moved to the trash of their volume, `$topdir/.Trash/$uid` or
+///   `$topdir/.Trash-$uid`, as the trash specification requires
+/// * folders on a volume without a usable trash are copied to the home trash, keeping timestamps and hard links
+pub fn move_to_trash<P: AsRef<Path>>(path: P) -> io::Result<()> {
+    let original = absolute(path)?;
+    let home = home_trash()?;
+    let trash = volume_trash(&original, &home).unwrap_or(home);
+    let (files, info) = (trash.join("files"), trash.join("info"));
+
+    create_dir_all(&files)?;
+    create_dir_all(&info)?;
+
+    let name = original.file_name()
+        .ok_or_else(|| io::Error::new(ErrorKind::InvalidInput, "path has no folder name"))?
+        .to_string_lossy()
+        .to_string();
+
+    let item = TrashedItem {
+        name,
+        original,
+        deleted: Local::now().format("%Y-%m-%dT%H:%M:%S").to_string(),
+        trash,
+    };
+
+    let (item, info_file) = reserve_trash_name(item, &files, &info)?;
+    let trashed = files.join(&item.name);
+
+    // a folder that reached the trash keeps its info file, even if removing what was left of it failed
+    move_folder(&item.original, &trashed).inspect_err(|_| {
+        if trashed.symlink_metadata().is_err() {
+            let _ = remove_file(&info_file);
+        }
+    })
+}
+
+/// Restores a folder trashed by cleaner to its original location
+pub fn restore_from_trash(item: &TrashedItem) -> io::Result<()> {
+    if item.original.symlink_metadata().is_ok() {
+        return Err(io::Error::new(ErrorKind::AlreadyExists, "original location is occupied"));
+    }
+
+    if let Some(parent) = item.original.parent() {
+        create_dir_all(parent)?;
+    }
+
+    move_folder(&item.trash.join("files").join(&item.name), &item.original)?;
+
+    remove_file(item.trash.join("info").join(format!("{}.trashinfo", item.name)))
+}
+
+/// Lists all folders in the home trash, and the trashes of mounted volumes, that were trashed by cleaner
+pub fn trashed_items() -> io::Result<Vec<TrashedItem>> {
+    let mut trashes = vec![home_trash()?];
+
+    trashes.extend(volume_trashes());
+
+    let mut items = trashes.iter().flat_map(|trash| trashed_in(trash)).collect::<Vec<_>>();
+
+    items.sort_by(|a, b| a.deleted.cmp(&b.deleted).then_with(|| a.original.cmp(&b.original)));
+
+    Ok(items)
+}
+
+/// Copies a folder and its contents, keeping timestamps, permissions and hard links between its files
+///
+/// * symbolic links are copied as symbolic links
+pub fn copy_folder(from: &Path, to: &Path) -> io::Result<()> {
+    copy_recursively(from, to, &mut HashMap::new())
+}
+
+/// Lists the folders in a trash that were trashed by cleaner
+fn trashed_in(trash: &Path) -> Vec<TrashedItem> {
+    let (files, info) = (trash.join("files"), trash.join("info"));
+
+    let Ok(entries) = read_dir(info) else {
+        return vec![];
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let name = file_name.strip_suffix(".trashinfo")?;
+            let trash_info = read_to_string(entry.path()).ok()?;
+
+            TrashedItem::parse(trash, name, &trash_info)
+        })
+        .filter(|item| files.join(&item.name).symlink_metadata().is_ok())
+        .collect()
+}
+
+/// Gets the user's home trash
+fn home_trash() -> io::Result<PathBuf> {
+    data_home()
+        .map(|data| data.join("Trash"))
+        .ok_or_else(|| io::Error::new(ErrorKind::NotFound, "could not determine trash location"))
+}
+
+/// Gets the trash of the volume a folder is on, none if it is on the file system of the home trash
+///
+/// * `$topdir/.Trash/$uid`, if the administrator created a sticky `$topdir/.Trash` that is not a symbolic link
+/// * otherwise `$topdir/.Trash-$uid`, created if needed, and only used if it is the user's own folder
+#[cfg(unix)]
+fn volume_trash(path: &Path, home_trash: &Path) -> Option<PathBuf> {
+    use std::fs::DirBuilder;
+    use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+
+    const STICKY: u32 = 0o1000;
+
+    let parent = path.parent()?.canonicalize().ok()?;
+    let device = parent.metadata().ok()?.dev();
+    let home_device = home_trash.ancestors().find_map(|folder| folder.metadata().ok())?.dev();
+
+    if device == home_device {
+        return None;
+    }
+
+    let topdir = parent.ancestors()
+        .take_while(|folder| folder.metadata().is_ok_and(|metadata| metadata.dev() == device))
+        .last()?;
+    let uid = user_id();
+    let shared = topdir.join(".Trash");
+
+    if shared.symlink_metadata().is_ok_and(|metadata| metadata.is_dir() && metadata.mode() & STICKY != 0) {
+        let trash = shared.join(uid.to_string());
+
+        if DirBuilder::new().recursive(true).mode(0o700).create(&trash).is_ok() {
+            return Some(trash);
+        }
+    }
+
+    let trash = topdir.join(format!(".Trash-{uid}"));
+
+    DirBuilder::new().recursive(true).mode(0o700).create(&trash).ok()?;
+
+    trash.symlink_metadata().ok()
+        .filter(|metadata| metadata.is_dir() && metadata.uid() == uid)
+        .map(|_| trash)
+}
+
+/// Gets the trash of the volume a folder is on, volumes have no trashes of their own on other platforms
+#[cfg(not(unix))]
+fn volume_trash(_path: &Path, _home_trash: &Path) -> Option<PathBuf> {
+    None
+}
+
+/// Lists the user's existing trashes of mounted volumes
+#[cfg(unix)]
+fn volume_trashes() -> Vec<PathBuf> {
+    let uid = user_id();
+    let mounts = read_to_string("/proc/self/mounts").unwrap_or_default();
+
+    let mut trashes = mounts.lines()
+        .filter_map(|mount| mount.split_whitespace().nth(1))
+        .map(unescape_mount_point)
+        .flat_map(|topdir| [topdir.join(".Trash").join(uid.to_string()), topdir.join(format!(".Trash-{uid}"))])
+        .filter(|trash| trash.is_dir())
+        .collect::<Vec<_>>();
+
+    trashes.sort();
+    trashes.dedup();
+
+    trashes
+}
+
+/// Lists the user's existing trashes of mounted volumes, volumes have no trashes of their own on other platforms
+#[cfg(not(unix))]
+const fn volume_trashes() -> Vec<PathBuf> {
+    Vec::new()
+}
+
+/// Decodes the octal escapes of a mount point in `/proc/self/mounts`, i.e. `\040` for a space
+#[cfg(unix)]
+fn unescape_mount_point(escaped: &str) -> PathBuf {
+    use std::ffi::OsString;
+    use std::os::unix::ffi::OsStringExt;
+
+    let escaped = escaped.as_bytes();
+    let mut bytes = Vec::with_capacity(escaped.len());
+    let mut idx = 0;
+
+    while let Some(&byte) = escaped.get(idx) {
+        let octal = escaped.get(idx + 1..idx + 4)
+            .filter(|_| byte == b'\\')
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u8::from_str_radix(digits, 8).ok());
+
+        if let Some(octal) = octal {
+            bytes.push(octal);
+            idx += 4;
+        } else {
+            bytes.push(byte);
+            idx += 1;
+        }
+    }
+
+    PathBuf::from(OsString::from_vec(bytes))
+}
+
+/// Gets the id of the user running cleaner
+#[cfg(unix)]
+fn user_id() -> u32 {
+    // SAFETY: getuid has no preconditions and can not fail
+    unsafe { libc::getuid() }
+}
+
+/// Atomically creates a trash info file with a unique name, adjusting the item's name if needed
+fn reserve_trash_name(
+    mut item: TrashedItem, files: &Path, info: &Path,
+) -> io::Result<(TrashedItem, PathBuf)> {
+    let base = item.name.clone();
+
+    for attempt in 1.. {
+        if attempt > 1 {
+            item.name = format!("{base}.{attempt}");
+        }
+
+        if files.join(&item.name).symlink_metadata().is_ok() {
+            continue;
+        }
+
+        let info_file = info.join(format!("{}.trashinfo", item.name));
+
+        match OpenOptions::new().write(true).create_new(true).open(&info_file) {
+            Ok(mut file) => {
+                file.write_all(item.trash_info().as_bytes())?;
+
+                return Ok((item, info_file));
+            }
+            Err(err) if err.kind() == ErrorKind::AlreadyExists => {}
+            Err(err) => return Err(err),
+        }
+    }
+
+    unreachable!()
+}
+
+/// Moves a folder, copying it when source and destination are on different file systems
+fn move_folder(from: &Path, to: &Path) -> io::Result<()> {
+    match rename(from, to) {
+        Err(err) if err.kind() == ErrorKind::CrossesDevices => {
+            copy_folder(from, to).inspect_err(|_| {
+                let _ = remove_dir_all(to);
+            })?;
+
+            if from.is_symlink() {
+                remove_file(from)
+            } else {
+                remove_dir_all(from)
+            }
+        }
+        result => result,
+    }
+}
+
+/// Copies a folder and its contents, files already copied through another hard link are linked instead
+fn copy_recursively(from: &Path, to: &Path, linked: &mut HashMap<(u64, u64), PathBuf>) -> io::Result<()> {
+    let metadata = from.symlink_metadata()?;
+
+    if metadata.is_symlink() {
+        return copy_symlink(from, to);
+    }
+
+    if metadata.is_dir() {
+        create_dir(to)?;
+
+        for entry in read_dir(from)? {
+            let entry = entry?;
+
+            copy_recursively(&entry.path(), &to.join(entry.file_name()), linked)?;
+        }
+
+        std::fs::set_permissions(to, metadata.permissions())?;
+
+        return copy_times(&metadata, to);
+    }
+
+    if let Some(key) = hard_link_key(&metadata) {
+        if let Some(copied) = linked.get(&key) {
+            return std::fs::hard_link(copied, to);
+        }
+
+        linked.insert(key, to.to_path_buf());
+    }
+
+    copy(from, to)?;
+
+    copy_times(&metadata, to)
+}
+
+/// Sets the access and modification times of a copy to those of the original
+fn copy_times(original: &Metadata, copy: &Path) -> io::Result<()> {
+    let times = FileTimes::new().set_accessed(original.accessed()?).set_modified(original.modified()?);
+
+    File::open(copy)?.set_times(times)
+}
+
+/// Identifies a file with more than one hard link, by its device and inode
+#[cfg(unix)]
+fn hard_link_key(metadata: &Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+
+    (metadata.nlink() > 1).then(|| (metadata.dev(), metadata.ino()))
+}
+
+/// Identifies a file with more than one hard link, hard links are copied as files on other platforms
+#[cfg(not(unix))]
+const fn hard_link_key(_metadata: &Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+#[cfg(unix)]
+fn copy_symlink(from: &Path, to: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(read_link(from)?, to)
+}
+
+#[cfg(windows)]
+fn copy_symlink(from: &Path, to: &Path) -> io::Result<()> {
+    let target = read_link(from)?;
+
+    if from.is_dir() {
+        std::os::windows::fs::symlink_dir(target, to)
+    } else {
+        std::os::windows::fs::symlink_file(target, to)
+    }
+}
@@ -0,0 +1,15 @@
+use std::env::var_os;
+use std::path::PathBuf;
+
+/// Gets the user's data folder, `$XDG_DATA_HOME` or `~/.local/share`
+pub fn data_home() -> Option<PathBuf> {
+    xdg_folder("XDG_DATA_HOME", ".local/share")
+}
+
+/// Gets an absolute folder from an XDG environment variable or a fallback relative to home
+fn xdg_folder(variable: &str, fallback: &str) -> Option<PathBuf> {
+    var_os(variable)
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| var_os("HOME").map(|home| PathBuf::from(home).join(fallback)))
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::path::{absolute, Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+
+use crate::error::Error;
+use crate::models::Ignored;
+
+/// Name of files with gitignore syntax rules of paths to skip, placed in any folder
+pub const IGNORE_FILE: &str = ".cleanerignore";
+
+/// Rules of paths to skip, from the configuration and `.cleanerignore` files
+///
+/// * `.cleanerignore` files apply to paths below their folder, including folders above a scanned path
+/// * rules of the closest `.cleanerignore` file take precedence, configuration rules apply last
+/// * rules can be shared by parallel traversals
+pub struct IgnoreRules {
+    /// Scanned path
+    root: PathBuf,
+
+    /// Absolute scanned path, used to match rules of folders above it
+    absolute: PathBuf,
+
+    /// Rules of the configuration, relative to the scanned path
+    global: Gitignore,
+
+    /// Rules of `.cleanerignore` files of folders above the scanned path, closest first
+    inherited: Vec<Gitignore>,
+
+    /// Rules of `.cleanerignore` files of folders under the scanned path, loaded as needed
+    folders: Mutex<HashMap<PathBuf, Option<Arc<Gitignore>>>>,
+
+    /// Errors reading rules, not yet reported
+    errors: Mutex<Vec<Error>>,
+
+    /// Paths skipped so far, and the rules that skipped them
+    ignored: Mutex<Vec<Ignored>>,
+}
+
+impl IgnoreRules {
+    /// Initializes the ignore rules of a scanned path, with rules from the configuration
+    pub fn new<P: AsRef<Path>>(root: P, global: &[String]) -> Self {
+        let root = root.as_ref().to_path_buf();
+        let absolute = absolute(&root).unwrap_or_else(|_| root.clone());
+        let mut errors = Vec::new();
+        let mut builder = GitignoreBuilder::new(&root);
+
+        for rule in global {
+            if let Err(source) = builder.add_line(None, rule) {
+                errors.push(Error::Ignore { path: None, source });
+            }
+        }
+
+        let global = builder.build().unwrap_or_else(|source| {
+            errors.push(Error::Ignore { path: None, source });
+
+            Gitignore::empty()
+        });
+
+        let inherited = absolute.ancestors()
+            .skip(1)
+            .filter_map(|folder| load_ignore_file(folder, &mut errors))
+            .collect();
+
+        Self {
+            root,
+            absolute,
+            global,
+            inherited,
+            folders: Mutex::default(),
+            errors: Mutex::new(errors),
+            ignored: Mutex::default(),
+        }
+    }
+
+    /// Paths skipped so far in path order, and the rules that skipped them
+    #[must_use]
+    pub fn ignored(&self) -> Vec<Ignored> {
+        let mut ignored = self.ignored.lock().unwrap_or_else(PoisonError::into_inner).clone();
+
+        ignored.sort_by(|left, right| left.path.cmp(&right.path));
+
+        ignored
+    }
+
+    /// Checks if a path should be skipped, skipped paths are recorded
+    pub fn is_ignored(&self, path: &Path, is_dir: bool) -> bool {
+        self.matched(path, is_dir)
+            .map(|ignored| self.ignored.lock().unwrap_or_else(PoisonError::into_inner).push(ignored))
+            .is_some()
+    }
+
+    /// Finds the rule a path should be skipped by, if any
+    pub fn matched(&self, path: &Path, is_dir: bool) -> Option<Ignored> {
+        let ignored = |glob: &ignore::gitignore::Glob| Ignored {
+            path: path.to_path_buf(),
+            rule: glob.original().to_string(),
+            source: glob.from().map(Path::to_path_buf),
+        };
+
+        for folder in path.ancestors().skip(1).take_while(|folder| folder.starts_with(&self.root)) {
+            match self.folder_rules(folder).as_ref().map(|rules| rules.matched(path, is_dir)) {
+                Some(Match::Ignore(glob)) => return Some(ignored(glob)),
+                Some(Match::Whitelist(_)) => return None,
+                _ => {}
+            }
+        }
+
+        if !self.inherited.is_empty() {
+            let absolute = path.strip_prefix(&self.root)
+                .map_or_else(|_| path.to_path_buf(), |relative| self.absolute.join(relative));
+
+            for rules in &self.inherited {
+                match rules.matched(&absolute, is_dir) {
+                    Match::Ignore(glob) => return Some(ignored(glob)),
+                    Match::Whitelist(_) => return None,
+                    Match::None => {}
+                }
+            }
+        }
+
+        match self.global.matched(path, is_dir) {
+            Match::Ignore(glob) => Some(ignored(glob)),
+            _ => None,
+        }
+    }
+
+    /// Takes the next error reading rules, if any
+    pub fn next_error(&self) -> Option<Error> {
+        self.errors.lock().unwrap_or_else(PoisonError::into_inner).pop()
+    }
+
+    /// Rules of a folder under the scanned path, loaded on first use
+    fn folder_rules(&self, folder: &Path) -> Option<Arc<Gitignore>> {
+        if let Some(rules) = self.folders.lock().unwrap_or_else(PoisonError::into_inner).get(folder) {
+            return rules.clone();
+        }
+
+        let mut errors = Vec::new();
+        let rules = load_ignore_file(folder, &mut errors).map(Arc::new);
+
+        self.errors.lock().unwrap_or_else(PoisonError::into_inner).extend(errors);
+        self.folders.lock().unwrap_or_else(PoisonError::into_inner)
+            .entry(folder.to_path_buf())
+            .or_insert(rules)
+            .clone()
+    }
+}
+
+/// Loads the `.cleanerignore` file of a folder, if it has one
+fn load_ignore_file(folder: &Path, errors: &mut Vec<Error>) -> Option<Gitignore> {
+    let path = folder.join(IGNORE_FILE);
+
+    if !path.is_file() {
+        return None;
+    }
+
+    let (rules, error) = Gitignore::new(&path);
+
+    if let Some(source) = error {
+        errors.push(Error::Ignore { path: Some(path), source });
+    }
+
+    Some(rules)
+}
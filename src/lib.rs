@@ -0,0 +1,53 @@
+#![doc = include_str!("../README.md")]
+
+#![deny(clippy::all)]
+#![deny(clippy::pedantic)]
+#![deny(clippy::nursery)]
+#![deny(clippy::cargo)]
+#![deny(missing_docs)]
+// ==============================================================
+#![allow(clippy::module_name_repetitions)]
+#![allow(clippy::items_after_statements)]
+#![allow(clippy::multiple_crate_versions)] // multiple bitflags versions
+// ==============================================================
+#![doc(html_root_url = "https://docs.rs/cleaner/0.11.0")]
+
+#[macro_use]
+extern crate serde;
+
+pub use classify::{
+    branch_name, classify_folder, classify_repo, git_ignored, git_tracked, has_local_branch,
+    is_branched, is_init_only, is_main_branch, uncommitted_changes,
+};
+pub use config::{
+    find_configuration, find_project_configuration, migrate_configuration, read_configuration, write_configuration,
+    CONFIGURATION_EXTENSIONS, DEFAULT_JUNK, DEFAULT_PLATFORMS, PROJECT_CONFIGURATION,
+};
+pub use error::Error;
+pub use fs::{disk_usage, last_activity};
+pub use ignores::{IgnoreRules, IGNORE_FILE};
+pub use migrations::CONFIGURATION_VERSION;
+pub use models::{
+    Age, AgeFilter, BranchName, BuildArtifacts, Configuration, ConfigurationFormat, EmptyCriteria, EmptyFolder, Filter,
+    Ignored, JournalEntry, Layer, LayeredConfiguration, Platform, Removal, RepoState, Selection, Size, TrashedItem,
+    Traversal,
+};
+pub use remove::{
+    remove_and_record, remove_folder, remove_folders, removal_history, restore_folder, trashed_folders,
+};
+pub use scan::{duplicate_roots, scan_builds, scan_empties, scan_repos};
+pub use walkers::{BuildsWalker, EmptiesWalker, ReposWalker};
+
+mod classify;
+mod config;
+mod error;
+mod fs;
+mod ignores;
+mod migrations;
+mod models;
+mod remove;
+mod scan;
+mod walkers;
+
+#[cfg(test)]
+mod tests;
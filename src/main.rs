@@ -1,4 +1,4 @@
-#![doc = include_str!("../README.md")]
+//! Cleaner command line interface, a thin layer over the cleaner library
 
 #![deny(clippy::all)]
 #![deny(clippy::pedantic)]
@@ -10,15 +10,15 @@
 #![allow(clippy::items_after_statements)]
 #![allow(clippy::multiple_crate_versions)] // multiple bitflags versions
 // ==============================================================
-#![doc(html_root_url = "https://docs.rs/cleaner/0.11.0")]
 
 #[macro_use]
 extern crate clap;
-#[macro_use]
-extern crate serde;
+
+use std::process::ExitCode;
 
 use clap::Parser;
 use once_cell::sync::Lazy;
+use rayon::ThreadPoolBuilder;
 
 use commands::builds::list_build_artifacts;
 use commands::builds::remove_build_artifacts;
@@ -26,86 +26,131 @@ use commands::builds::remove_build_artifacts;
 use crate::cli::commands::builds::Builds;
 use crate::cli::commands::empties::Empties;
 use crate::cli::commands::repos::Repos;
+use crate::cli::commands::restore::Restore;
 use crate::cli::commands::supported::Supported;
 use crate::cli::commands::Commands;
-use crate::cli::selection::Selection;
+use crate::cli::exit::Exit;
+use crate::cli::prompt::Prompt;
 use crate::cli::CLI;
 use crate::commands::empties::{list_empties, remove_empties};
+use crate::commands::history::list_history;
 use crate::commands::repos::{
     list_outdated_repos, list_repos, list_repos_that_are_branched, list_repos_that_are_init_only,
     list_repos_with_branch, list_repos_with_detached_head, list_repos_with_errors,
     list_repos_with_uncommitted_changes, list_repos_without_configured_remotes,
     list_up_to_date_repos,
 };
+use crate::commands::restore::{list_trashed, restore_trashed};
 use crate::commands::supported::{
-    manage_configuration, reset_configuration, show_configuration, supported_platforms,
+    manage_configuration, reset_configuration, show_configuration, supported_platform_origins,
+    supported_platforms,
+};
+use cleaner::{LayeredConfiguration, Removal};
+use crate::utils::{
+    display_failure_and_exit, errors_reported, finish_output, load_configuration, output_text,
+    report_collected_errors, set_fail_fast, set_output_format, set_traversal,
 };
-use crate::models::Platform;
-use crate::utils::load_supported_platforms;
 
 #[doc(hidden)]
 mod cli;
 #[doc(hidden)]
 mod commands;
 #[doc(hidden)]
-mod models;
-#[doc(hidden)]
 mod utils;
 
 #[cfg(test)]
+#[path = "tests/bin/mod.rs"]
 mod tests;
 
-/// Definition of supported development platforms
+/// Configuration of supported development platforms and ignore rules of the current folder
 #[doc(hidden)]
-static PLATFORMS: Lazy<Vec<Platform>> = Lazy::new(load_supported_platforms);
+static CONFIGURATION: Lazy<LayeredConfiguration> = Lazy::new(load_configuration);
 
 /// Cleaner command line parsing and command execution
 #[doc(hidden)]
-fn main() {
+fn main() -> ExitCode {
     let cli = CLI::parse();
 
-    println!();
-
-    match &cli.commands {
-        Commands::Builds(Builds::List { path, types }) =>
-            list_build_artifacts(path, types, &PLATFORMS),
-        Commands::Builds(Builds::Remove { path, types, confirmed }) =>
-            remove_build_artifacts(path, types, &PLATFORMS, *confirmed),
-        Commands::Empties(Empties::List { path, hidden }) =>
-            list_empties(path, *hidden, &PLATFORMS),
-        Commands::Empties(Empties::Remove { path, confirmed, hidden }) =>
-            remove_empties(path, *confirmed, *hidden, &PLATFORMS),
-        Commands::Supported(Supported::List) =>
-            supported_platforms(&PLATFORMS),
+    set_output_format(cli.format);
+    set_fail_fast(cli.fail_fast);
+
+    if let Some(threads) = cli.threads
+        && let Err(err) = ThreadPoolBuilder::new().num_threads(threads.get()).build_global() {
+        display_failure_and_exit(&format!("Failed to start {threads} search threads: {err}"));
+    }
+
+    if let Some(options) = cli.commands.traversal() {
+        set_traversal(options);
+    }
+
+    output_text("");
+
+    let exit = match &cli.commands {
+        Commands::Builds { command: Builds::List { paths, types, criteria, show_ignored }, .. } =>
+            list_build_artifacts(paths, types, *criteria, *show_ignored),
+        Commands::Builds {
+            command: Builds::Remove { paths, types, criteria, free, confirmed, interactive, stale, dry_run, trash }, ..
+        } =>
+            remove_build_artifacts(
+                paths, types, *criteria, *free,
+                Prompt::new(*confirmed, *interactive, *stale), Removal::new(*dry_run, *trash),
+            ),
+        Commands::Empties { command: Empties::List { paths, criteria }, .. } =>
+            list_empties(paths, *criteria),
+        Commands::Empties {
+            command: Empties::Remove { paths, confirmed, interactive, stale, criteria, dry_run, trash }, ..
+        } =>
+            remove_empties(
+                paths, Prompt::new(*confirmed, *interactive, *stale), *criteria, Removal::new(*dry_run, *trash),
+            ),
+        Commands::History(history) =>
+            list_history(history),
+        Commands::Supported(Supported::List { origin: false }) =>
+            supported_platforms(&CONFIGURATION.configuration.platforms),
+        Commands::Supported(Supported::List { origin: true }) =>
+            supported_platform_origins(&CONFIGURATION),
         Commands::Supported(Supported::Path) =>
             show_configuration(),
         Commands::Supported(Supported::Manage) =>
             manage_configuration(),
         Commands::Supported(Supported::Reset { confirmed }) =>
             reset_configuration(*confirmed),
-        Commands::Repos(Repos::Branched { path }) =>
-            list_repos_that_are_branched(path),
-        Commands::Repos(Repos::Changes { path }) =>
-            list_repos_with_uncommitted_changes(path),
-        Commands::Repos(Repos::Detached { path }) =>
-            list_repos_with_detached_head(path),
-        Commands::Repos(Repos::Error { path }) =>
-            list_repos_with_errors(path),
-        Commands::Repos(Repos::Init { path }) =>
-            list_repos_that_are_init_only(path),
-        Commands::Repos(Repos::List { path }) =>
-            list_repos(path),
-        Commands::Repos(Repos::Local { path }) =>
-            list_repos_without_configured_remotes(path),
-        Commands::Repos(Repos::Main { path }) =>
-            list_repos_with_branch(path, "main"),
-        Commands::Repos(Repos::Master { path }) =>
-            list_repos_with_branch(path, "master"),
-        Commands::Repos(Repos::Outdated { path, filter, main }) =>
-            list_outdated_repos(path, *filter, *main),
-        Commands::Repos(Repos::UpToDate { path, main }) =>
-            list_up_to_date_repos(path, *main)
-    }
+        Commands::Repos { command: Repos::Branched { paths }, .. } =>
+            list_repos_that_are_branched(paths),
+        Commands::Repos { command: Repos::Changes { paths }, .. } =>
+            list_repos_with_uncommitted_changes(paths),
+        Commands::Repos { command: Repos::Detached { paths }, .. } =>
+            list_repos_with_detached_head(paths),
+        Commands::Repos { command: Repos::Error { paths }, .. } =>
+            list_repos_with_errors(paths),
+        Commands::Repos { command: Repos::Init { paths }, .. } =>
+            list_repos_that_are_init_only(paths),
+        Commands::Repos { command: Repos::List { paths }, .. } =>
+            list_repos(paths),
+        Commands::Repos { command: Repos::Local { paths }, .. } =>
+            list_repos_without_configured_remotes(paths),
+        Commands::Repos { command: Repos::Main { paths }, .. } =>
+            list_repos_with_branch(paths, "main"),
+        Commands::Repos { command: Repos::Master { paths }, .. } =>
+            list_repos_with_branch(paths, "master"),
+        Commands::Repos { command: Repos::Outdated { paths, filter, main }, .. } =>
+            list_outdated_repos(paths, *filter, *main),
+        Commands::Repos { command: Repos::UpToDate { paths, main }, .. } =>
+            list_up_to_date_repos(paths, *main),
+        Commands::Restore(Restore::List { path }) =>
+            list_trashed(path),
+        Commands::Restore(Restore::Items { path, confirmed }) =>
+            restore_trashed(path, *confirmed),
+    };
+
+    output_text("");
+    report_collected_errors();
+    finish_output();
 
-    println!();
+    // reported errors take precedence over matches, but not over cancelling
+    if errors_reported() {
+        exit.max(Exit::PartialFailure).into()
+    } else {
+        exit.into()
+    }
 }
@@ -0,0 +1,61 @@
+use serde_json::{Map, Value};
+
+/// Version of the configuration document cleaner reads and writes
+pub const CONFIGURATION_VERSION: u64 = 2;
+
+/// Migrations of configuration documents, in order, the migration at index `n` upgrades version `n + 1`
+const MIGRATIONS: [fn(Value) -> Value; 1] = [into_versioned_document];
+
+/// Version of a configuration document
+///
+/// * a list of platforms, or a document without a version, is version 1
+///
+/// # Errors
+///
+/// * document is neither a list of platforms nor a document, or its version is not a positive number
+pub fn version_of(document: &Value) -> Result<u64, String> {
+    match document {
+        Value::Array(_) => Ok(1),
+        Value::Object(settings) => settings.get("version").map_or(Ok(1), |version| {
+            version.as_u64()
+                .filter(|version| *version > 0)
+                .ok_or_else(|| format!("invalid configuration version {version}"))
+        }),
+        _ => Err(String::from("expected a list of platforms, or a configuration document")),
+    }
+}
+
+/// Migrates a configuration document to the current version, one version at a time
+///
+/// * returns the migrated document and the version it was migrated from
+///
+/// # Errors
+///
+/// * document has no valid version, or is newer than this version of cleaner
+pub fn migrate(document: Value) -> Result<(Value, u64), String> {
+    let version = version_of(&document)?;
+
+    if version > CONFIGURATION_VERSION {
+        return Err(format!(
+            "configuration version {version} is newer than version {CONFIGURATION_VERSION} this cleaner supports"
+        ));
+    }
+
+    let pending = usize::try_from(version - 1).unwrap_or(usize::MAX);
+    let migrated = MIGRATIONS.iter().skip(pending).fold(document, |document, migration| migration(document));
+
+    Ok((migrated, version))
+}
+
+/// Migrates version 1 to 2, a list of platforms becomes a document of platforms, documents gain a version
+fn into_versioned_document(document: Value) -> Value {
+    let mut settings = match document {
+        Value::Array(platforms) => Map::from_iter([(String::from("platforms"), Value::Array(platforms))]),
+        Value::Object(settings) => settings,
+        document => return document,
+    };
+
+    settings.insert(String::from("version"), Value::from(2));
+
+    Value::Object(settings)
+}
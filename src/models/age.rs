@@ -0,0 +1,79 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+/// Units an age can be expressed in, and their length in seconds
+const UNITS: [(char, u64); 5] = [
+    ('w', 7 * 24 * 60 * 60),
+    ('d', 24 * 60 * 60),
+    ('h', 60 * 60),
+    ('m', 60),
+    ('s', 1),
+];
+
+/// Describes an amount of time, i.e. `30d` or `6w`
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Age(pub Duration);
+
+impl Age {
+    /// Age of a point in time, relative to now
+    #[must_use]
+    pub fn since(time: SystemTime) -> Self {
+        Self(SystemTime::now().duration_since(time).unwrap_or_default())
+    }
+}
+
+impl Display for Age {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        let seconds = self.0.as_secs();
+
+        // weeks are skipped, days are easier to read
+        let (unit, length) = UNITS[1..].iter()
+            .find(|(_, length)| seconds >= *length)
+            .unwrap_or(&UNITS[4]);
+
+        fmt.write_fmt(format_args!("{}{unit}", seconds / length))
+    }
+}
+
+impl FromStr for Age {
+    type Err = String;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let src = src.trim().to_lowercase();
+        let invalid = || format!("invalid age {src:?}, expected a number followed by a unit: w, d, h, m or s");
+
+        let unit = src.chars().last().ok_or_else(invalid)?;
+        let (_, length) = UNITS.iter().find(|(u, _)| *u == unit).ok_or_else(invalid)?;
+        let value = src[..src.len() - 1].trim().parse::<u64>().map_err(|_| invalid())?;
+
+        Ok(Self(Duration::from_secs(value.saturating_mul(*length))))
+    }
+}
+
+/// Filters projects by their last activity
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct AgeFilter {
+    /// Only include projects not modified within this age
+    pub older_than: Option<Age>,
+
+    /// Only include projects modified within this age
+    pub newer_than: Option<Age>,
+}
+
+impl AgeFilter {
+    /// Checks if any age filtering is defined
+    #[must_use]
+    pub const fn is_defined(&self) -> bool {
+        self.older_than.is_some() || self.newer_than.is_some()
+    }
+
+    /// Checks if a project's last activity matches the filter
+    #[must_use]
+    pub fn matches(&self, activity: SystemTime) -> bool {
+        let age = Age::since(activity);
+
+        self.older_than.is_none_or(|older_than| age > older_than) &&
+            self.newer_than.is_none_or(|newer_than| age <= newer_than)
+    }
+}
@@ -2,8 +2,12 @@ use std::fmt::{Display, Formatter};
 
 use git2::Oid;
 
+/// Name of a checked out branch
 pub enum BranchName<'a> {
+    /// Name of a branch
     Branch(&'a str),
+
+    /// Commit of a detached head
     Head(Oid),
 }
 
@@ -1,3 +1,5 @@
+use std::time::SystemTime;
+
 /// Describes an existing build artifact
 pub struct BuildArtifacts<'a> {
     /// Development platform name
@@ -5,4 +7,9 @@ pub struct BuildArtifacts<'a> {
 
     /// Build artifact folder located
     pub folder: String,
+
+    /// Last time a file in the project, outside `.git` and build artifacts, was modified
+    ///
+    /// * only determined when filtering by age, none if the project has no files of its own
+    pub activity: Option<SystemTime>,
 }
@@ -0,0 +1,94 @@
+use serde::de::Error;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::config::DEFAULT_JUNK;
+use crate::migrations::{migrate, CONFIGURATION_VERSION};
+use crate::models::{Filter, Platform};
+
+/// Describes cleaner's configuration, supported platforms, paths to skip and junk files
+///
+/// * configurations are versioned documents, older versions are migrated when they are read
+#[derive(Clone)]
+pub struct Configuration {
+    /// Supported development platforms
+    pub platforms: Vec<Platform>,
+
+    /// Gitignore syntax rules of paths to skip, relative to a scanned path
+    pub ignore: Vec<String>,
+
+    /// File names that do not keep a folder from being empty, wildcards [*, ?] accepted
+    pub junk: Vec<Filter>,
+}
+
+impl Configuration {
+    /// Checks if the junk files are the default junk files
+    pub(crate) fn default_junk(&self) -> bool {
+        self.junk.len() == DEFAULT_JUNK.len()
+            && self.junk.iter().zip(DEFAULT_JUNK).all(|(junk, default)| junk.as_ref() == default)
+    }
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self { platforms: Vec::new(), ignore: Vec::new(), junk: default_junk() }
+    }
+}
+
+/// Configuration document of the current version
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Settings {
+    #[serde(rename = "version")]
+    _version: u64,
+    platforms: Vec<Platform>,
+    #[serde(default)]
+    ignore: Vec<String>,
+    #[serde(default = "default_junk")]
+    junk: Vec<Filter>,
+}
+
+/// Default junk files
+fn default_junk() -> Vec<Filter> {
+    DEFAULT_JUNK.into_iter().map(|junk| Filter::new(junk.to_string())).collect()
+}
+
+impl Serialize for Configuration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut settings = serializer.serialize_struct("Configuration", 4)?;
+
+        settings.serialize_field("version", &CONFIGURATION_VERSION)?;
+        settings.serialize_field("platforms", &self.platforms)?;
+
+        if self.ignore.is_empty() {
+            settings.skip_field("ignore")?;
+        } else {
+            settings.serialize_field("ignore", &self.ignore)?;
+        }
+
+        if self.default_junk() {
+            settings.skip_field("junk")?;
+        } else {
+            settings.serialize_field("junk", &self.junk)?;
+        }
+
+        settings.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Configuration {
+    /// Deserializes a configuration document of any version, migrating it to the current version
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (document, _) = migrate(Value::deserialize(deserializer)?).map_err(D::Error::custom)?;
+        let Settings { platforms, ignore, junk, .. } = Settings::deserialize(document).map_err(D::Error::custom)?;
+
+        Ok(Self { platforms, ignore, junk })
+    }
+}
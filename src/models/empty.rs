@@ -0,0 +1,56 @@
+use std::path::PathBuf;
+
+use crate::models::Filter;
+
+/// Describes an empty folder
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmptyFolder {
+    /// Empty folder located
+    pub folder: PathBuf,
+
+    /// Junk files in the folder, i.e. `.DS_Store`, removed along with it
+    ///
+    /// * a folder with junk files is only empty because its files are all junk
+    pub junk: Vec<PathBuf>,
+}
+
+/// Describes which empty folders a scan includes
+#[derive(Clone, Debug, Default)]
+pub struct EmptyCriteria {
+    /// File names that do not keep a folder from being empty, i.e. `.DS_Store`
+    pub junk: Vec<Filter>,
+
+    /// Includes empty hidden folders
+    pub show_hidden: bool,
+
+    /// Only includes empty folders git would ignore in their enclosing repository, or that are outside a repository
+    pub respect_gitignore: bool,
+
+    /// Includes folders tracked by git or marked by a placeholder file, i.e. `.gitkeep`
+    pub include_tracked: bool,
+}
+
+impl EmptyCriteria {
+    /// Initializes criteria that treat folders with only junk files as empty
+    #[must_use]
+    pub fn new(junk: &[Filter]) -> Self {
+        Self { junk: junk.to_vec(), ..Self::default() }
+    }
+
+    /// Sets whether empty hidden folders are included
+    #[must_use]
+    pub const fn with_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+
+        self
+    }
+
+    /// Sets whether only empty folders git would ignore are included, and whether folders git tracks are included
+    #[must_use]
+    pub const fn with_git(mut self, respect_gitignore: bool, include_tracked: bool) -> Self {
+        self.respect_gitignore = respect_gitignore;
+        self.include_tracked = include_tracked;
+
+        self
+    }
+}
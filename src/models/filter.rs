@@ -4,11 +4,13 @@ use serde::de::{Error, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use wildmatch::WildMatch;
 
+/// File name filter, wildcards [*, ?] accepted
 #[derive(Debug, Clone)]
 pub struct Filter(WildMatch, String);
 
 impl Filter {
     /// Initializes a new `Filter`
+    #[must_use]
     pub fn new(filter: String) -> Self {
         Self(WildMatch::new(&filter), filter)
     }
@@ -0,0 +1,53 @@
+use std::ffi::OsStr;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Cause of failure parsing or formatting a configuration
+type FormatError = Box<dyn std::error::Error + Send + Sync>;
+
+/// File format of a configuration, chosen by the extension of its file
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ConfigurationFormat {
+    /// `.json`, and files with any other extension
+    Json,
+
+    /// `.toml`
+    Toml,
+
+    /// `.yaml` or `.yml`
+    Yaml,
+}
+
+impl ConfigurationFormat {
+    /// Format of a configuration file, JSON unless its extension is TOML or YAML
+    #[must_use]
+    pub fn of<P: AsRef<Path>>(path: P) -> Self {
+        let extension = path.as_ref().extension().and_then(OsStr::to_str).map(str::to_ascii_lowercase);
+
+        match extension.as_deref() {
+            Some("toml") => Self::Toml,
+            Some("yaml" | "yml") => Self::Yaml,
+            _ => Self::Json,
+        }
+    }
+
+    /// Parses a document of this format
+    pub(crate) fn parse<T: DeserializeOwned>(self, document: &str) -> Result<T, FormatError> {
+        match self {
+            Self::Json => Ok(serde_json::from_str(document)?),
+            Self::Toml => Ok(toml::from_str(document)?),
+            Self::Yaml => Ok(serde_yaml::from_str(document)?),
+        }
+    }
+
+    /// Formats a document in this format
+    pub(crate) fn format<T: Serialize>(self, document: &T) -> Result<String, FormatError> {
+        match self {
+            Self::Json => Ok(serde_json::to_string(document)?),
+            Self::Toml => Ok(toml::to_string(document)?),
+            Self::Yaml => Ok(serde_yaml::to_string(document)?),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+/// Describes a path skipped by an ignore rule
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ignored {
+    /// Skipped path
+    pub path: PathBuf,
+
+    /// Rule as it was written
+    pub rule: String,
+
+    /// `.cleanerignore` file of the rule, none if the rule is from the configuration
+    pub source: Option<PathBuf>,
+}
+
+impl Display for Ignored {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.source {
+            Some(source) =>
+                fmt.write_fmt(format_args!("\"{}\" in {}", self.rule, source.to_string_lossy())),
+            None =>
+                fmt.write_fmt(format_args!("\"{}\" in configuration", self.rule)),
+        }
+    }
+}
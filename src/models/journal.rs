@@ -0,0 +1,86 @@
+use std::path::{absolute, Path, PathBuf};
+
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, SecondsFormat, TimeDelta};
+
+use crate::models::Age;
+
+/// Format of journal timestamps written before they had an offset, local date and time
+const LEGACY_TIMESTAMP: &str = "%Y-%m-%dT%H:%M:%S";
+
+/// Describes a folder cleaner removed, as recorded in the removal journal
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Local date and time the folder was removed, RFC 3339 with its offset, i.e. `2024-08-31T22:32:08+02:00`
+    ///
+    /// * entries written by earlier versions have no offset, i.e. `2024-08-31T22:32:08`, they are local time
+    pub removed: String,
+
+    /// Absolute path of the removed folder
+    pub path: PathBuf,
+
+    /// Development platform of removed build artifacts, none for an empty folder
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+
+    /// On disk size in bytes of the removed folder
+    pub size: u64,
+
+    /// Whether the user confirmed or picked the removal, otherwise it was automatic, i.e. `--confirmed`
+    pub confirmed: bool,
+
+    /// Whether the folder was moved to the trash, otherwise it was permanently deleted
+    #[serde(default)]
+    pub trashed: bool,
+}
+
+impl JournalEntry {
+    /// Initializes an entry for a folder removed now
+    pub fn new<P: AsRef<Path>>(path: P, platform: Option<String>, size: u64, confirmed: bool) -> Self {
+        let path = path.as_ref();
+
+        Self {
+            removed: Local::now().to_rfc3339_opts(SecondsFormat::Secs, false),
+            path: absolute(path).unwrap_or_else(|_| path.to_path_buf()),
+            platform,
+            size,
+            confirmed,
+            trashed: false,
+        }
+    }
+
+    /// Marks the entry as moved to the trash
+    #[must_use]
+    pub const fn with_trashed(mut self, trashed: bool) -> Self {
+        self.trashed = trashed;
+
+        self
+    }
+
+    /// Date and time the folder was removed, none if the entry's timestamp can not be parsed
+    #[must_use]
+    pub fn removed_at(&self) -> Option<DateTime<FixedOffset>> {
+        DateTime::parse_from_rfc3339(&self.removed).ok().or_else(|| {
+            NaiveDateTime::parse_from_str(&self.removed, LEGACY_TIMESTAMP).ok()?
+                .and_local_timezone(Local)
+                .earliest()
+                .map(|removed| removed.fixed_offset())
+        })
+    }
+
+    /// Checks if the folder was removed within an age, relative to now
+    ///
+    /// * an entry whose timestamp can not be parsed is never within an age
+    #[must_use]
+    pub fn is_within(&self, age: Age) -> bool {
+        let cutoff = TimeDelta::from_std(age.0).ok()
+            .and_then(|age| Local::now().checked_sub_signed(age));
+
+        cutoff.is_none_or(|cutoff| self.removed_at().is_some_and(|removed| removed >= cutoff))
+    }
+
+    /// Describes how the folder was removed, `confirmed` or `automatic`
+    #[must_use]
+    pub const fn approval(&self) -> &'static str {
+        if self.confirmed { "confirmed" } else { "automatic" }
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+
+use crate::config::DEFAULT_PLATFORMS;
+use crate::models::{Configuration, Platform};
+
+/// Configuration layer, in order of precedence, later layers override earlier layers
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Layer {
+    /// Built-in default platforms
+    Default,
+
+    /// System wide configuration file, i.e. `/etc/cleaner/supported-platforms.json`
+    System(PathBuf),
+
+    /// User configuration file
+    User(PathBuf),
+
+    /// `.cleaner.json` of a project, found walking up from a scanned path
+    Project(PathBuf),
+}
+
+/// Configuration merged from layers, remembering the layer each platform came from
+///
+/// * platforms of a later layer add to, or replace platforms of the same name of, earlier layers
+/// * disabled platforms of a later layer remove platforms of the same name of earlier layers
+/// * ignore rules of all layers apply
+/// * junk files of a later layer replace those of earlier layers, unless they are the default junk files
+#[derive(Clone)]
+pub struct LayeredConfiguration {
+    /// Merged configuration
+    pub configuration: Configuration,
+
+    /// Layer of each platform of the merged configuration, in the same order
+    origins: Vec<Layer>,
+}
+
+impl LayeredConfiguration {
+    /// Initializes a layered configuration with a first layer
+    #[must_use]
+    pub fn new(layer: &Layer, configuration: Configuration) -> Self {
+        let mut layered = Self { configuration: Configuration::default(), origins: Vec::new() };
+
+        layered.apply(layer, configuration);
+
+        layered
+    }
+
+    /// Merges a layer over the current configuration
+    pub fn apply(&mut self, layer: &Layer, configuration: Configuration) {
+        let default_junk = configuration.default_junk();
+        let Configuration { platforms, ignore, junk } = configuration;
+
+        for platform in platforms {
+            let existing = self.configuration.platforms.iter().position(|existing| *existing == platform);
+
+            match existing {
+                Some(index) if platform.disabled => {
+                    self.configuration.platforms.remove(index);
+                    self.origins.remove(index);
+                }
+                Some(index) => {
+                    self.configuration.platforms[index] = platform;
+                    self.origins[index] = layer.clone();
+                }
+                None if platform.disabled => {}
+                None => {
+                    self.configuration.platforms.push(platform);
+                    self.origins.push(layer.clone());
+                }
+            }
+        }
+
+        self.configuration.ignore.extend(ignore);
+
+        if !default_junk {
+            self.configuration.junk = junk;
+        }
+    }
+
+    /// Platforms of the merged configuration, with the layer each came from
+    pub fn origins(&self) -> impl Iterator<Item = (&Platform, &Layer)> {
+        self.configuration.platforms.iter().zip(&self.origins)
+    }
+}
+
+impl Default for LayeredConfiguration {
+    /// Built-in default platforms
+    fn default() -> Self {
+        let defaults = serde_json::from_str(DEFAULT_PLATFORMS).unwrap_or_default();
+
+        Self::new(&Layer::Default, defaults)
+    }
+}
+
+impl Display for Layer {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => fmt.write_str("default"),
+            Self::System(path) => fmt.write_fmt(format_args!("system \"{}\"", path.to_string_lossy())),
+            Self::User(path) => fmt.write_fmt(format_args!("user \"{}\"", path.to_string_lossy())),
+            Self::Project(path) => fmt.write_fmt(format_args!("project \"{}\"", path.to_string_lossy())),
+        }
+    }
+}
@@ -1,9 +1,35 @@
+pub use age::{Age, AgeFilter};
 pub use branch::BranchName;
 pub use build::BuildArtifacts;
+pub use configuration::Configuration;
+pub use empty::{EmptyCriteria, EmptyFolder};
 pub use filter::Filter;
+pub use format::ConfigurationFormat;
+pub use ignored::Ignored;
+pub use journal::JournalEntry;
+pub use layer::{Layer, LayeredConfiguration};
 pub use platform::Platform;
+pub use removal::Removal;
+pub use repo::RepoState;
+pub use selection::Selection;
+pub use size::Size;
+pub use trashed::TrashedItem;
+pub use traversal::Traversal;
 
+mod age;
 mod branch;
 mod build;
+mod configuration;
+mod empty;
 mod filter;
+mod format;
+mod ignored;
+mod journal;
+mod layer;
 mod platform;
+mod removal;
+mod repo;
+mod selection;
+mod size;
+mod trashed;
+mod traversal;
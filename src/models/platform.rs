@@ -9,14 +9,27 @@ pub struct Platform {
     pub name: String,
 
     /// Expected build artifact folder names of platform
+    #[serde(default)]
     pub folders: Vec<String>,
 
     /// Associated files and file extensions that mark the platform
+    #[serde(default)]
     pub associated: Vec<Filter>,
+
+    /// Removes a platform of the same name defined by an earlier configuration layer
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
 }
 
 impl Platform {
+    /// Initializes an entry disabling a platform of an earlier configuration layer
+    #[must_use]
+    pub fn disable(name: &str) -> Self {
+        Self { name: name.to_string(), folders: Vec::new(), associated: Vec::new(), disabled: true }
+    }
+
     /// Checks if two supported platforms produce the same effect
+    #[must_use]
     pub fn same_as(&self, other: &Self) -> bool {
         self.folders.len() == other.folders.len() &&
             self.associated.len() == other.associated.len() &&
@@ -0,0 +1,36 @@
+use std::fmt::{Display, Formatter};
+
+/// Describes how matched folders are removed
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Removal {
+    /// Permanently deletes folders
+    Delete,
+
+    /// Only reports what would be removed
+    DryRun,
+
+    /// Moves folders to the trash
+    Trash,
+}
+
+impl Removal {
+    /// Determines removal from command line switches, a dry run takes precedence
+    #[must_use]
+    pub const fn new(dry_run: bool, trash: bool) -> Self {
+        match (dry_run, trash) {
+            (true, _) => Self::DryRun,
+            (false, true) => Self::Trash,
+            (false, false) => Self::Delete,
+        }
+    }
+}
+
+impl Display for Removal {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Delete => fmt.write_str("removed"),
+            Self::DryRun => fmt.write_str("would be removed"),
+            Self::Trash => fmt.write_str("moved to trash"),
+        }
+    }
+}
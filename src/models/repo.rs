@@ -0,0 +1,18 @@
+/// Describes the state of a repo's checked out branch
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RepoState {
+    /// Checked out branch, or commit of a detached head, none if nothing has been committed
+    pub branch: Option<String>,
+
+    /// Head is detached from a branch
+    pub detached: bool,
+
+    /// Uncommitted changes, including untracked files
+    pub changes: Option<usize>,
+
+    /// Commits the checked out branch is ahead of its upstream
+    pub ahead: Option<usize>,
+
+    /// Commits the checked out branch is behind its upstream
+    pub behind: Option<usize>,
+}
@@ -1,18 +1,17 @@
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
-use crate::utils::list_output;
-
 /// Generic comma delimited multiple string values or "all" argument
-#[derive(Clone, Debug, Eq, PartialEq, Subcommand)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Selection {
     /// Indicates "all" should be included
-    #[clap(verbatim_doc_comment)]
     All,
 
     /// Defines only specific values
-    #[clap(verbatim_doc_comment)]
-    Select { values: Vec<String> },
+    Select {
+        /// Selected values
+        values: Vec<String>,
+    },
 }
 
 impl Selection {
@@ -25,6 +24,7 @@ impl Selection {
     }
 
     /// Checks to see if value is included
+    #[must_use]
     pub fn matches(&self, checked: &str) -> bool {
         match self {
             Self::All => true,
@@ -33,6 +33,7 @@ impl Selection {
     }
 
     /// Helper method for determining if input requires pluralization
+    #[must_use]
     pub const fn pluralize<'a>(&'a self, plural: &'a str) -> &'a str {
         match self {
             Self::All => plural,
@@ -47,8 +48,11 @@ impl Display for Selection {
         match self {
             Self::All =>
                 fmt.write_str("all"),
-            Self::Select { values } =>
-                fmt.write_str(&list_output(values))
+            Self::Select { values } => match values.split_last() {
+                None => Ok(()),
+                Some((last, [])) => fmt.write_str(last),
+                Some((last, values)) => fmt.write_fmt(format_args!("{} & {last}", values.join(", "))),
+            }
         }
     }
 }
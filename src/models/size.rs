@@ -0,0 +1,88 @@
+use std::fmt::{Display, Formatter};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+use std::str::FromStr;
+
+/// Binary units used for displaying a size
+const UNITS: [&str; 6] = ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+
+/// Describes an amount of disk space in bytes
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Size(pub u64);
+
+impl Add for Size {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl AddAssign for Size {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Display for Size {
+    #[allow(clippy::cast_precision_loss)] // precision is irrelevant for display
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0 < 1024 {
+            return fmt.write_fmt(format_args!("{} B", self.0));
+        }
+
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+
+        fmt.write_fmt(format_args!("{value:.1} {}", UNITS[unit]))
+    }
+}
+
+impl FromStr for Size {
+    type Err = String;
+
+    /// Parses a size with an optional binary unit, i.e. `500M`, `1.5GiB` or `10 GB`
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let src = src.trim();
+        let invalid = || format!("invalid size {src:?}, expected a number optionally followed by a unit, i.e. B, K, KB or KiB");
+
+        let split = src.find(|c: char| c.is_ascii_alphabetic()).unwrap_or(src.len());
+        let (value, unit) = src.split_at(split);
+        let value = value.trim().parse::<f64>().map_err(|_| invalid())?;
+
+        if !value.is_finite() || value < 0.0 {
+            return Err(invalid());
+        }
+
+        let unit = unit.to_ascii_uppercase();
+
+        let multiplier = if unit.is_empty() || unit == "B" {
+            1.0
+        } else {
+            let position = UNITS.iter().position(|binary| is_unit(&unit, binary)).ok_or_else(invalid)?;
+
+            1024_f64.powi(i32::try_from(position).map_err(|_| invalid())? + 1)
+        };
+
+        Ok(Self((value * multiplier).min(u64::MAX as f64) as u64))
+    }
+}
+
+/// Checks if an upper case unit is a binary unit, its letter alone, followed by B or followed by iB, i.e. K, KB or KiB
+fn is_unit(unit: &str, binary: &str) -> bool {
+    let letter = &binary[..1];
+
+    unit == letter || unit.strip_prefix(letter) == Some("B") || unit == binary.to_ascii_uppercase()
+}
+
+impl Sum for Size {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
@@ -0,0 +1,127 @@
+use std::ffi::OsString;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+/// Section header of a trash info file
+const TRASH_INFO: &str = "[Trash Info]";
+
+/// Key marking a trash info file as written by cleaner
+const CLEANER_KEY: &str = "X-Cleaner";
+
+/// Describes a folder moved to the trash by cleaner
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrashedItem {
+    /// Name of the folder in the trash
+    pub name: String,
+
+    /// Original location of the folder
+    pub original: PathBuf,
+
+    /// Local date and time the folder was trashed, i.e. `2024-08-31T22:32:08`
+    pub deleted: String,
+
+    /// Trash the folder is in, the home trash or the trash of the folder's volume
+    pub trash: PathBuf,
+}
+
+impl TrashedItem {
+    /// Parses the contents of a trash info file of a trash, only folders trashed by cleaner are parsed
+    pub fn parse(trash: &Path, name: &str, trash_info: &str) -> Option<Self> {
+        let mut lines = trash_info.lines().map(str::trim);
+        let mut original = None;
+        let mut deleted = None;
+        let mut cleaner = false;
+
+        if lines.next() != Some(TRASH_INFO) {
+            return None;
+        }
+
+        for (key, value) in lines.filter_map(|line| line.split_once('=')) {
+            match key {
+                "Path" => original = decode_path(value),
+                "DeletionDate" => deleted = Some(value.to_string()),
+                CLEANER_KEY => cleaner = value == "true",
+                _ => {}
+            }
+        }
+
+        if !cleaner {
+            return None;
+        }
+
+        Some(Self {
+            name: name.to_string(),
+            original: original?,
+            deleted: deleted?,
+            trash: trash.to_path_buf(),
+        })
+    }
+
+    /// Generates the contents of a trash info file
+    #[must_use]
+    pub fn trash_info(&self) -> String {
+        format!(
+            "{TRASH_INFO}\nPath={}\nDeletionDate={}\n{CLEANER_KEY}=true\n",
+            encode_path(&self.original),
+            self.deleted
+        )
+    }
+}
+
+/// Percent encodes a path as required by the trash specification
+fn encode_path(path: &Path) -> String {
+    let mut encoded = String::new();
+
+    for byte in path_bytes(path) {
+        if byte.is_ascii_alphanumeric() || b"-_.~/".contains(&byte) {
+            encoded.push(char::from(byte));
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+
+    encoded
+}
+
+/// Decodes a percent encoded path of a trash info file
+fn decode_path(encoded: &str) -> Option<PathBuf> {
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut source = encoded.bytes();
+
+    while let Some(byte) = source.next() {
+        if byte == b'%' {
+            let hex = [source.next()?, source.next()?];
+            let hex = std::str::from_utf8(&hex).ok()?;
+
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+
+    Some(PathBuf::from(path_from_bytes(bytes)))
+}
+
+#[cfg(unix)]
+fn path_bytes(path: &Path) -> Vec<u8> {
+    use std::os::unix::ffi::OsStrExt;
+
+    path.as_os_str().as_bytes().to_vec()
+}
+
+#[cfg(not(unix))]
+fn path_bytes(path: &Path) -> Vec<u8> {
+    path.to_string_lossy().as_bytes().to_vec()
+}
+
+#[cfg(unix)]
+fn path_from_bytes(bytes: Vec<u8>) -> OsString {
+    use std::os::unix::ffi::OsStringExt;
+
+    OsString::from_vec(bytes)
+}
+
+#[cfg(not(unix))]
+fn path_from_bytes(bytes: Vec<u8>) -> OsString {
+    OsString::from(String::from_utf8_lossy(&bytes).to_string())
+}
@@ -0,0 +1,125 @@
+use std::path::{absolute, Path};
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+
+use crate::error::Error;
+
+/// Describes how the folders of a scanned path are traversed
+#[derive(Clone, Debug, Default)]
+pub struct Traversal {
+    /// Gitignore syntax rules of paths to skip, relative to the scanned path, i.e. from the configuration
+    pub ignore: Vec<String>,
+
+    /// Deepest folder depth below the scanned path results are reported from, unlimited if none
+    pub max_depth: Option<usize>,
+
+    /// Shallowest folder depth below the scanned path results are reported from
+    pub min_depth: usize,
+
+    /// Searches folders symbolic links point to, links that loop back to a folder containing them are not followed
+    pub follow_links: bool,
+
+    /// Does not search folders on other file systems than the scanned path, i.e. mounted drives
+    pub one_file_system: bool,
+
+    /// Glob patterns of folders that are never searched
+    exclude: GlobSet,
+
+    /// Checks if the traversal is cancelled, i.e. interrupted by the user
+    cancelled: Option<fn() -> bool>,
+}
+
+impl Traversal {
+    /// Initializes a traversal that skips paths matched by gitignore syntax rules
+    #[must_use]
+    pub fn new(ignore: &[String]) -> Self {
+        Self { ignore: ignore.to_vec(), ..Self::default() }
+    }
+
+    /// Replaces the gitignore syntax rules of paths to skip, i.e. with those of the scanned path's configuration
+    #[must_use]
+    pub fn with_ignore(mut self, ignore: &[String]) -> Self {
+        self.ignore = ignore.to_vec();
+
+        self
+    }
+
+    /// Limits the folder depths below the scanned path results are reported from
+    #[must_use]
+    pub const fn with_depth(mut self, min_depth: usize, max_depth: Option<usize>) -> Self {
+        self.min_depth = min_depth;
+        self.max_depth = max_depth;
+
+        self
+    }
+
+    /// Sets whether symbolic links to folders are followed, and whether other file systems are searched
+    #[must_use]
+    pub const fn with_links(mut self, follow_links: bool, one_file_system: bool) -> Self {
+        self.follow_links = follow_links;
+        self.one_file_system = one_file_system;
+
+        self
+    }
+
+    /// Cancels the traversal once a check succeeds, folders are no longer searched once cancelled
+    ///
+    /// * the check is made before each folder is visited, it is expected to be cheap, i.e. reading a flag
+    #[must_use]
+    pub const fn with_cancel(mut self, cancelled: fn() -> bool) -> Self {
+        self.cancelled = Some(cancelled);
+
+        self
+    }
+
+    /// Excludes folders matched by glob patterns, excluded folders are never searched
+    ///
+    /// * patterns are matched against a folder's name, its path relative to the scanned path and its absolute path
+    ///
+    /// # Errors
+    ///
+    /// * a pattern is not a valid glob
+    pub fn with_exclude<S: AsRef<str>>(mut self, patterns: &[S]) -> Result<Self, Error> {
+        let mut builder = GlobSetBuilder::new();
+
+        for pattern in patterns {
+            let pattern = pattern.as_ref();
+            let glob = Glob::new(pattern).map_err(|source| Error::Exclude { pattern: pattern.to_string(), source })?;
+
+            builder.add(glob);
+        }
+
+        self.exclude = builder.build().map_err(|source| Error::Exclude { pattern: patterns.iter()
+            .map(AsRef::as_ref)
+            .collect::<Vec<_>>()
+            .join(", "), source })?;
+
+        Ok(self)
+    }
+
+    /// Checks if a folder below the scanned path is excluded
+    #[must_use]
+    pub fn excludes(&self, root: &Path, folder: &Path) -> bool {
+        if self.exclude.is_empty() {
+            return false;
+        }
+
+        let relative = folder.strip_prefix(root).unwrap_or(folder);
+
+        folder.file_name().is_some_and(|name| self.exclude.is_match(name))
+            || self.exclude.is_match(relative)
+            || absolute(folder).is_ok_and(|absolute| self.exclude.is_match(absolute))
+    }
+
+    /// Checks if the traversal is cancelled, a traversal without a cancel check is never cancelled
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.is_some_and(|cancelled| cancelled())
+    }
+
+    /// Checks if results are reported from a folder depth below the scanned path
+    #[must_use]
+    pub fn reports(&self, depth: usize) -> bool {
+        depth >= self.min_depth && self.max_depth.is_none_or(|max_depth| depth <= max_depth)
+    }
+}
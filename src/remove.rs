@@ -0,0 +1,91 @@
+use std::fs::{remove_dir, remove_dir_all, remove_file};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::error::Error;
+use crate::fs::{append_to_journal, journal_entries, move_to_trash, restore_from_trash, trashed_items};
+use crate::models::{JournalEntry, Removal, TrashedItem};
+
+/// Removes a folder as described by removal
+///
+/// * a symbolic link to a folder is unlinked, the folder it points to is never deleted
+///
+/// # Errors
+///
+/// * folder can not be deleted or moved to the trash
+pub fn remove_folder<P: AsRef<Path>>(path: P, removal: Removal) -> Result<(), Error> {
+    let path = path.as_ref();
+
+    let result = match removal {
+        Removal::Delete if path.is_symlink() => unlink(path),
+        Removal::Delete => remove_dir_all(path),
+        Removal::DryRun => Ok(()),
+        Removal::Trash => move_to_trash(path),
+    };
+
+    result.map_err(|source| Error::Remove { path: path.to_path_buf(), source })
+}
+
+/// Removes folders as described by removal, each folder is removed as it is iterated
+pub fn remove_folders<I, P>(folders: I, removal: Removal) -> impl Iterator<Item = Result<PathBuf, Error>>
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<Path>,
+{
+    folders.into_iter().map(move |folder| {
+        let folder = folder.as_ref();
+
+        remove_folder(folder, removal).map(|()| folder.to_path_buf())
+    })
+}
+
+/// Restores a folder cleaner moved to the trash, to its original location
+///
+/// # Errors
+///
+/// * original location already exists, or the folder can not be moved back
+pub fn restore_folder(item: &TrashedItem) -> Result<(), Error> {
+    restore_from_trash(item).map_err(|source| Error::Restore { path: item.original.clone(), source })
+}
+
+/// Lists folders cleaner moved to the trash
+///
+/// # Errors
+///
+/// * trash can not be read
+pub fn trashed_folders() -> Result<Vec<TrashedItem>, Error> {
+    trashed_items().map_err(|source| Error::Trash { source })
+}
+
+/// Removes the folder of a journal entry as described by removal, recording it in the append only removal journal,
+/// in the user's data folder
+///
+/// * dry runs are not removed, nor recorded
+///
+/// # Errors
+///
+/// * folder can not be deleted or moved to the trash, the removal is not recorded
+/// * [`Error::Journal`], the folder was removed but the journal can not be created or written to
+pub fn remove_and_record(entry: JournalEntry, removal: Removal) -> Result<(), Error> {
+    remove_folder(&entry.path, removal)?;
+
+    if removal == Removal::DryRun {
+        return Ok(());
+    }
+
+    append_to_journal(&entry.with_trashed(removal == Removal::Trash)).map_err(|source| Error::Journal { source })
+}
+
+/// Lists removals recorded in the removal journal, oldest first
+///
+/// # Errors
+///
+/// * journal can not be read
+pub fn removal_history() -> Result<Vec<JournalEntry>, Error> {
+    journal_entries().map_err(|source| Error::Journal { source })
+}
+
+/// Removes a symbolic link itself, links to folders are removed as folders on some platforms, i.e. windows
+fn unlink(link: &Path) -> io::Result<()> {
+    remove_file(link).or_else(|err| remove_dir(link).map_err(|_| err))
+}
@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+use std::fs::canonicalize;
+use std::path::{absolute, Path, PathBuf};
+
+use crate::models::{AgeFilter, EmptyCriteria, Platform, Selection, Traversal};
+use crate::walkers::{BuildsWalker, EmptiesWalker, ReposWalker};
+
+/// Scans a path for build artifacts of selected platforms, skipping ignored and excluded paths
+///
+/// * only build artifacts within the depth limits of the traversal are included
+/// * respecting gitignore only includes build artifacts git would ignore, or that are outside a repository
+pub fn scan_builds<'a, P: AsRef<Path>>(
+    path: P, selection: &'a Selection, platforms: &'a [Platform], age: AgeFilter,
+    traversal: &Traversal, respect_gitignore: bool,
+) -> BuildsWalker<'a> {
+    BuildsWalker::new(selection, path, platforms, age, traversal, respect_gitignore)
+}
+
+/// Scans a path for empty folders, build artifact folders of platforms, ignored and excluded paths are skipped
+///
+/// * folders with excluded sub folders are not empty
+/// * only empty folders within the depth limits of the traversal are included
+/// * folders with only junk files of the criteria, i.e. `.DS_Store`, are empty
+/// * respecting gitignore only includes empty folders git would ignore, or that are outside a repository
+/// * folders tracked by git or marked by a placeholder file, i.e. `.gitkeep`, are only included if tracked is included
+pub fn scan_empties<P: AsRef<Path>>(
+    path: P, platforms: &[Platform], traversal: &Traversal, criteria: &EmptyCriteria,
+) -> EmptiesWalker {
+    let skipped = platforms.iter()
+        .flat_map(|p| p.folders.iter().cloned())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    EmptiesWalker::new(path, skipped, traversal, criteria)
+}
+
+/// Scans a path for git repositories, skipping ignored and excluded paths
+///
+/// * only repositories within the depth limits of the traversal are included
+pub fn scan_repos<P: AsRef<Path>>(path: P, traversal: &Traversal) -> ReposWalker {
+    ReposWalker::new(path, traversal)
+}
+
+/// Finds the first identical root of each root path, none if the root is the first of its kind
+///
+/// * roots are compared by their canonical paths, symbolic links are resolved
+/// * roots inside another root are not duplicates, a search of the outer root may never reach them
+#[must_use]
+pub fn duplicate_roots<P: AsRef<Path>>(roots: &[P]) -> Vec<Option<usize>> {
+    let canonical = roots.iter()
+        .map(|root| canonical_root(root.as_ref()))
+        .collect::<Vec<_>>();
+
+    canonical.iter()
+        .enumerate()
+        .map(|(idx, root)| canonical[..idx].iter().position(|other| other == root))
+        .collect()
+}
+
+/// Canonical path of a root, falls back to its absolute path if it can not be resolved
+fn canonical_root(root: &Path) -> PathBuf {
+    canonicalize(root)
+        .or_else(|_| absolute(root))
+        .unwrap_or_else(|_| root.to_path_buf())
+}
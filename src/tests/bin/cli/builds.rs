@@ -0,0 +1,17 @@
+use cleaner::Size;
+
+use crate::cli::commands::builds::parse_free;
+
+#[test]
+fn given_a_size_to_free_it_should_parse_it() {
+    let actual = parse_free("1K");
+
+    assert_eq!(Ok(Size(1024)), actual);
+}
+
+#[test]
+fn given_nothing_to_free_it_should_be_rejected() {
+    let actual = parse_free("0B");
+
+    assert!(actual.is_err());
+}
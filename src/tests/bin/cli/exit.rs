@@ -0,0 +1,91 @@
+use std::fs::{create_dir_all, remove_dir_all};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use cleaner::Selection;
+
+use crate::cli::commands::history::History;
+use crate::cli::exit::Exit;
+use crate::commands::history::list_history;
+use crate::tests::{exit_code_of, in_child_test, temp_folder};
+use crate::utils::validate_path;
+
+#[test]
+fn given_found_matches_it_should_exit_found() {
+    let expected = Exit::Found;
+    let actual = Exit::found(true);
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_no_matches_it_should_exit_not_found() {
+    let expected = Exit::NotFound;
+    let actual = Exit::found(false);
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_a_partial_failure_it_should_take_precedence_over_matches() {
+    let expected = Exit::PartialFailure;
+    let actual = Exit::Found.max(Exit::PartialFailure);
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_cancelled_it_should_take_precedence_over_a_partial_failure() {
+    let expected = Exit::Cancelled;
+    let actual = Exit::Cancelled.max(Exit::PartialFailure);
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_an_exit_it_should_convert_to_its_exit_code() {
+    let expected = ExitCode::from(3);
+    let actual = ExitCode::from(Exit::PartialFailure);
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_a_failure_it_should_take_precedence_over_cancelled() {
+    let expected = Exit::Failed;
+    let actual = Exit::Cancelled.max(Exit::Failed);
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_a_missing_path_it_should_exit_invalid() {
+    if in_child_test() {
+        validate_path(PathBuf::from(std::env::var_os("XDG_DATA_HOME").unwrap()).join("missing"));
+    }
+
+    let folder = temp_folder("exit-invalid");
+    let actual = exit_code_of(concat!(module_path!(), "::given_a_missing_path_it_should_exit_invalid"), &folder);
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(Some(Exit::Invalid as i32), actual);
+}
+
+#[test]
+fn given_an_unreadable_journal_it_should_exit_failed() {
+    if in_child_test() {
+        list_history(&History { path: None, types: Selection::All, within: None, by: None });
+    }
+
+    let folder = temp_folder("exit-failed");
+
+    // a folder in place of the journal can not be read
+    create_dir_all(folder.join("cleaner").join("history.ndjson")).unwrap();
+
+    let actual = exit_code_of(concat!(module_path!(), "::given_an_unreadable_journal_it_should_exit_failed"), &folder);
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(Some(Exit::Failed as i32), actual);
+}
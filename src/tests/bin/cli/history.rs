@@ -0,0 +1,19 @@
+use crate::cli::commands::history::Period;
+
+#[test]
+fn given_a_timestamp_it_should_total_by_its_month() {
+    const EXPECTED: &str = "2024-08";
+
+    let actual = Period::Month.of("2024-08-31T22:32:08");
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_malformed_timestamp_it_should_total_by_the_whole_timestamp() {
+    const EXPECTED: &str = "2024";
+
+    let actual = Period::Day.of("2024");
+
+    assert_eq!(EXPECTED, actual);
+}
@@ -0,0 +1,5 @@
+mod builds;
+mod exit;
+mod history;
+mod prompt;
+mod record;
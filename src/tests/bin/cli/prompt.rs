@@ -0,0 +1,34 @@
+use cleaner::Age;
+
+use crate::cli::prompt::Prompt;
+
+#[test]
+fn given_confirmed_and_interactive_it_should_not_prompt() {
+    let stale = "30d".parse::<Age>().unwrap();
+
+    let expected = Prompt::Confirmed;
+    let actual = Prompt::new(true, true, stale);
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_interactive_it_should_pick_with_stale_age() {
+    let stale = "6w".parse::<Age>().unwrap();
+
+    let expected = Some(stale);
+    let actual = Prompt::new(false, true, stale).picker();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_neither_switch_it_should_confirm_each_folder() {
+    let stale = "30d".parse::<Age>().unwrap();
+
+    let expected = Prompt::Each;
+    let actual = Prompt::new(false, false, stale);
+
+    assert_eq!(expected, actual);
+    assert!(!actual.is_confirmed());
+}
@@ -0,0 +1,55 @@
+use std::io;
+use std::path::Path;
+
+use cleaner::Error;
+
+use crate::cli::record::{Record, RecordKind};
+
+#[test]
+fn given_a_record_it_should_serialize_only_defined_fields() {
+    const EXPECTED: &str = r#"{"kind":"artifact","platform":"Rust","size":42}"#;
+
+    let record = Record {
+        platform: Some(String::from("Rust")),
+        size: Some(42),
+        ..Record::new(RecordKind::Artifact)
+    };
+
+    let actual = serde_json::to_string(&record).unwrap();
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_path_under_a_root_a_record_should_have_a_relative_path() {
+    let expected = Path::new("project").join("target").to_string_lossy().to_string();
+
+    let root = Path::new("/").join("work");
+    let record = Record::new(RecordKind::Artifact).with_path(root.join("project").join("target"), &root);
+
+    assert_eq!(Some(expected), record.relative_path);
+}
+
+#[test]
+fn given_an_error_message_a_record_should_be_an_error() {
+    let record = Record::new(RecordKind::Repo).with_error("failed");
+
+    assert_eq!(RecordKind::Error, record.kind);
+    assert_eq!(Some(String::from("failed")), record.message);
+}
+
+#[test]
+fn given_an_error_it_should_be_an_error_record_of_its_path() {
+    let path = Path::new("project").join("target");
+
+    let err = Error::Restore {
+        path: path.clone(),
+        source: io::Error::new(io::ErrorKind::AlreadyExists, "exists"),
+    };
+
+    let actual = Record::from(&err);
+
+    assert_eq!(RecordKind::Error, actual.kind);
+    assert_eq!(Some(path.to_string_lossy().to_string()), actual.path);
+    assert_eq!(Some(err.to_string()), actual.message);
+}
@@ -0,0 +1,168 @@
+use std::fs::{create_dir_all, remove_dir_all, write, File, FileTimes};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+use cleaner::{disk_usage, Removal, Selection, Size, PROJECT_CONFIGURATION};
+
+use crate::cli::commands::builds::ArtifactFilter;
+use crate::cli::exit::Exit;
+use crate::cli::prompt::Prompt;
+use crate::commands::builds::{build_artifacts_handler, remove_build_artifacts};
+use crate::tests::{isolated, temp_folder};
+use crate::utils::{clear_interrupt, interrupt, Roots};
+
+/// Creates a rust project last changed a number of years after the epoch, returns its build artifacts folder
+fn rust_project(folder: &Path, name: &str, years: u64) -> PathBuf {
+    let project = folder.join(name);
+    let target = project.join("target");
+    let changed = SystemTime::UNIX_EPOCH + Duration::from_hours(24 * 365 * years);
+
+    create_dir_all(&target).unwrap();
+    write(target.join("app"), vec![0; 64 * 1024]).unwrap();
+    write(project.join("Cargo.toml"), "[package]").unwrap();
+
+    File::options().write(true).open(project.join("Cargo.toml")).unwrap()
+        .set_times(FileTimes::new().set_modified(changed)).unwrap();
+
+    target
+}
+
+#[test]
+fn given_space_to_free_it_should_remove_least_recently_used_projects_until_enough_is_freed() {
+    let _isolated = isolated();
+    let folder = temp_folder("builds-free");
+    let newest = rust_project(&folder, "newest", 50);
+    let oldest = rust_project(&folder, "oldest", 30);
+    let older = rust_project(&folder, "older", 40);
+    let free = disk_usage(&oldest) + Size(1);
+
+    let exit = remove_build_artifacts(
+        &[folder.to_string_lossy().to_string()], &Selection::All, ArtifactFilter::default(), Some(free),
+        Prompt::Confirmed, Removal::Delete,
+    );
+
+    let actual = [&oldest, &older, &newest].map(|target| target.exists());
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(Exit::Found, exit);
+    assert_eq!([false, false, true], actual);
+}
+
+#[test]
+fn given_space_to_free_it_should_remove_projects_without_files_of_their_own_last() {
+    let _isolated = isolated();
+    let folder = temp_folder("builds-free-undated");
+    let undated = folder.join("undated").join("target");
+    let oldest = rust_project(&folder, "oldest", 30);
+    let newest = rust_project(&folder, "newest", 50);
+
+    // a project marked by a folder, not a file, has no files of its own
+    create_dir_all(folder.join("undated").join("Cargo.toml")).unwrap();
+    create_dir_all(&undated).unwrap();
+    write(undated.join("app"), vec![0; 64 * 1024]).unwrap();
+
+    let free = disk_usage(&oldest) + Size(1);
+
+    let exit = remove_build_artifacts(
+        &[folder.to_string_lossy().to_string()], &Selection::All, ArtifactFilter::default(), Some(free),
+        Prompt::Confirmed, Removal::Delete,
+    );
+
+    let actual = [&oldest, &newest, &undated].map(|target| target.exists());
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(Exit::Found, exit);
+    assert_eq!([false, false, true], actual);
+}
+
+#[test]
+fn given_nested_roots_it_should_handle_each_artifact_once_and_total_it_for_the_first_root_it_was_found_in() {
+    let _isolated = isolated();
+    let folder = temp_folder("builds-roots");
+
+    // the search of the outer root yields node_modules, it never reaches the project vendored in it
+    create_dir_all(folder.join("web")).unwrap();
+    write(folder.join("web").join("package.json"), "{}").unwrap();
+
+    let node_modules = folder.join("web").join("node_modules");
+    let vendored = rust_project(&node_modules, "vendored", 50);
+    let other = rust_project(&folder, "other", 50);
+    let paths = [vendored.parent().unwrap(), &folder, &folder.join("web"), &folder]
+        .map(|path| path.to_string_lossy().to_string());
+    let handled = Mutex::new(Vec::new());
+
+    let (exit, totals, _) = build_artifacts_handler(
+        &Roots::new(&paths), &Selection::All, ArtifactFilter::default(), None, None,
+        |artifact, _, _| {
+            handled.lock().unwrap().push(PathBuf::from(&artifact.folder));
+
+            Ok(true)
+        },
+    );
+
+    let expected_totals = vec![disk_usage(&vendored), disk_usage(&other) + disk_usage(&node_modules), Size::default()];
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(Exit::Found, exit);
+    assert_eq!(vec![vendored, other, node_modules], handled.into_inner().unwrap());
+    assert_eq!(expected_totals, totals);
+}
+
+#[test]
+fn given_an_interrupt_it_should_stop_searching_and_removing_and_exit_cancelled() {
+    let _isolated = isolated();
+    let folder = temp_folder("builds-interrupted");
+    let targets = ["first", "second", "third"].map(|name| rust_project(&folder, name, 50));
+
+    interrupt();
+
+    let exit = remove_build_artifacts(
+        &[folder.to_string_lossy().to_string()], &Selection::All, ArtifactFilter::default(), None,
+        Prompt::Confirmed, Removal::Delete,
+    );
+
+    clear_interrupt();
+
+    let actual = targets.map(|target| target.exists());
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(Exit::Cancelled, exit);
+    assert_eq!([true, true, true], actual);
+}
+
+#[test]
+fn given_roots_with_their_own_project_configurations_each_should_be_searched_with_its_own_platforms() {
+    let _isolated = isolated();
+    let folder = temp_folder("builds-root-configurations");
+    let (without_rust, with_rust) = (folder.join("without-rust"), folder.join("with-rust"));
+    let included = rust_project(&with_rust, "project", 50);
+
+    rust_project(&without_rust, "project", 50);
+
+    write(
+        without_rust.join(PROJECT_CONFIGURATION),
+        r#"{ "version": 2, "platforms": [{ "name": "Rust", "disabled": true }] }"#,
+    ).unwrap();
+
+    let paths = [&without_rust, &with_rust].map(|path| path.to_string_lossy().to_string());
+    let handled = Mutex::new(Vec::new());
+
+    let (exit, _, _) = build_artifacts_handler(
+        &Roots::new(&paths), &Selection::All, ArtifactFilter::default(), None, None,
+        |artifact, _, _| {
+            handled.lock().unwrap().push(PathBuf::from(&artifact.folder));
+
+            Ok(true)
+        },
+    );
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(Exit::Found, exit);
+    assert_eq!(vec![included], handled.into_inner().unwrap());
+}
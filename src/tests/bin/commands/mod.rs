@@ -0,0 +1,3 @@
+mod builds;
+mod repos;
+mod supported;
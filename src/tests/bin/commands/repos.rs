@@ -0,0 +1,39 @@
+use std::fs::remove_dir_all;
+use std::path::Path;
+
+use git2::{Error, Repository};
+use rayon::ThreadPoolBuilder;
+
+use crate::commands::repos::check_repos;
+use crate::tests::{isolated, temp_folder};
+
+/// Checks all repos in a folder with a number of threads, returns the output of each repo in order
+fn list_all(folder: &Path, threads: usize) -> Vec<String> {
+    let pool = ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+    let paths = [folder.to_string_lossy().to_string()];
+
+    pool.install(|| check_repos(&paths, |_, _| (true, None::<String>), None::<&fn(Error, &str) -> bool>))
+        .iter()
+        .flat_map(|(_, output)| output.texts().into_iter().map(str::to_string))
+        .collect()
+}
+
+#[test]
+fn given_more_threads_repos_should_be_listed_in_the_same_order() {
+    let _isolated = isolated();
+    let folder = temp_folder("repos-threads");
+
+    for outer in 0..4 {
+        for inner in 0..4 {
+            Repository::init(folder.join(format!("{outer}")).join(format!("{inner}"))).unwrap();
+        }
+    }
+
+    let expected = list_all(&folder, 1);
+    let actual = list_all(&folder, 8);
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(16, expected.len());
+    assert_eq!(expected, actual);
+}
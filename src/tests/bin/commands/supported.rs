@@ -0,0 +1,43 @@
+use std::fs::remove_file;
+
+use cleaner::{read_configuration, Configuration, Platform, DEFAULT_PLATFORMS};
+
+use crate::commands::supported::save_platforms;
+use crate::tests::isolated;
+use crate::utils::path_of_supported_platforms_configuration;
+
+#[test]
+fn given_managed_platforms_save_platforms_should_only_save_changes_to_the_base_layers() {
+    let _isolated = isolated();
+    let mut platforms = serde_json::from_str::<Configuration>(DEFAULT_PLATFORMS).unwrap().platforms;
+    let deleted = platforms.remove(0);
+    let added = Platform {
+        name: String::from("Zig"),
+        folders: vec![String::from("zig-out")],
+        associated: Vec::new(),
+        disabled: false,
+    };
+
+    platforms[0].folders.push(String::from("out"));
+    platforms.push(added);
+
+    let changed = platforms[0].clone();
+
+    assert!(save_platforms(&platforms));
+
+    let path = path_of_supported_platforms_configuration();
+    let saved = read_configuration(&path).unwrap();
+
+    remove_file(&path).unwrap();
+
+    let actual = saved.platforms.iter()
+        .map(|platform| (platform.name.clone(), platform.disabled, platform.folders.clone()))
+        .collect::<Vec<_>>();
+    let expected = vec![
+        (changed.name, false, changed.folders),
+        (String::from("Zig"), false, vec![String::from("zig-out")]),
+        (deleted.name, true, vec![]),
+    ];
+
+    assert_eq!(expected, actual);
+}
@@ -0,0 +1,70 @@
+use std::fs::{create_dir_all, remove_dir_all};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::{Mutex, MutexGuard, Once, PoisonError};
+
+mod cli;
+mod commands;
+mod utils;
+
+/// Creates a fresh temporary folder for a test
+fn temp_folder(name: &str) -> PathBuf {
+    let folder = std::env::temp_dir().join(format!("cleaner-bin-{name}-{}", std::process::id()));
+
+    if folder.exists() {
+        remove_dir_all(&folder).unwrap();
+    }
+
+    create_dir_all(&folder).unwrap();
+
+    folder
+}
+
+/// Runs a test that handles folders on its own, isolated from the user's configuration and removal journal
+///
+/// * handlers share global state, i.e. interrupts, tests holding the guard do not run concurrently
+fn isolated() -> MutexGuard<'static, ()> {
+    static ENVIRONMENT: Once = Once::new();
+    static SERIAL: Mutex<()> = Mutex::new(());
+
+    ENVIRONMENT.call_once(|| {
+        let folder = temp_folder("environment");
+
+        // SAFETY: set once, before any test reads them, std serializes its own access to the environment
+        unsafe {
+            std::env::set_var("CLEANER_CONFIG", folder.join("supported-platforms.json"));
+            std::env::set_var("XDG_DATA_HOME", &folder);
+        }
+    });
+
+    SERIAL.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Environment variable of a test re-run in a child process
+const CHILD_TEST: &str = "CLEANER_CHILD_TEST";
+
+/// Checks if a test is re-run in a child process, where it runs code that exits the process
+fn in_child_test() -> bool {
+    std::env::var_os(CHILD_TEST).is_some()
+}
+
+/// Re-runs a test in a child process, with its configuration and data in a folder, returns the child's exit code
+///
+/// * `test` is the test's full path, i.e. `concat!(module_path!(), "::given_...")`
+/// * a child test that does not exit fails, instead of re-running itself
+fn exit_code_of(test: &str, environment: &Path) -> Option<i32> {
+    let name = test.split_once("::").map_or(test, |(_, name)| name);
+
+    assert!(!in_child_test(), "{name} did not exit");
+
+    Command::new(std::env::current_exe().unwrap())
+        .args([name, "--exact", "--test-threads=1"])
+        .env(CHILD_TEST, name)
+        .env("CLEANER_CONFIG", environment.join("supported-platforms.json"))
+        .env("XDG_DATA_HOME", environment)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .unwrap()
+        .code()
+}
@@ -1,2 +1,3 @@
 mod display;
+mod output;
 mod platform;
@@ -0,0 +1,48 @@
+use std::io::{self, Write};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::cli::record::{Record, RecordKind};
+use crate::utils::write_csv;
+
+/// Writer shared by threads, each write is appended at once
+#[derive(Clone, Default)]
+struct Shared(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Shared {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn given_rows_written_by_several_threads_the_csv_header_should_be_written_once_before_any_row() {
+    let header = Mutex::new(false);
+    let writer = Shared::default();
+
+    thread::scope(|scope| {
+        for _ in 0..8 {
+            let (header, mut writer) = (&header, writer.clone());
+
+            scope.spawn(move || {
+                for _ in 0..50 {
+                    write_csv(&mut writer, header, &Record::new(RecordKind::Artifact));
+                }
+            });
+        }
+    });
+
+    let output = String::from_utf8(writer.0.lock().unwrap().clone()).unwrap();
+    let lines = output.lines().collect::<Vec<_>>();
+    let columns = Record::COLUMNS.join(",");
+
+    assert_eq!(Some(&columns.as_str()), lines.first());
+    assert_eq!(1, lines.iter().filter(|line| **line == columns).count());
+    assert_eq!(401, lines.len());
+}
@@ -0,0 +1,69 @@
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use crate::utils::{
+    path_of_supported_platforms_configuration, resolve_configuration_location, ConfigurationPath,
+    ConfigurationSource,
+};
+
+#[test]
+fn given_a_request_for_configuration_path_the_path_should_point_to_configuration_json() {
+    const EXPECTED: &str = "supported-platforms.json";
+
+    let actual = path_of_supported_platforms_configuration()
+        .to_string_lossy()
+        .to_string();
+
+    assert_eq!(EXPECTED, &actual[actual.len() - EXPECTED.len()..]);
+}
+
+#[test]
+fn given_cleaner_config_it_should_take_precedence_over_xdg_config_home() {
+    let expected = Some(ConfigurationPath {
+        path: PathBuf::from("/etc/cleaner.json"),
+        source: ConfigurationSource::Environment,
+    });
+
+    let actual = resolve_configuration_location(
+        Some(OsString::from("/etc/cleaner.json")),
+        Some(OsString::from("/home/user/.xdg")),
+        Some(OsString::from("/home/user")),
+    );
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_xdg_config_home_it_should_resolve_in_its_cleaner_folder() {
+    let expected = Some(ConfigurationPath {
+        path: PathBuf::from("/home/user/.xdg/cleaner/supported-platforms.json"),
+        source: ConfigurationSource::XdgConfigHome,
+    });
+
+    let actual = resolve_configuration_location(
+        None, Some(OsString::from("/home/user/.xdg")), Some(OsString::from("/home/user")),
+    );
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_a_relative_xdg_config_home_it_should_resolve_in_home_config_folder() {
+    let expected = Some(ConfigurationPath {
+        path: PathBuf::from("/home/user/.config/cleaner/supported-platforms.json"),
+        source: ConfigurationSource::Home,
+    });
+
+    let actual = resolve_configuration_location(
+        Some(OsString::new()), Some(OsString::from(".xdg")), Some(OsString::from("/home/user")),
+    );
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_no_environment_it_should_not_resolve_a_location() {
+    let actual = resolve_configuration_location(None, None, None);
+
+    assert!(actual.is_none());
+}
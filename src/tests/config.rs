@@ -0,0 +1,236 @@
+use std::fs::{create_dir_all, read_to_string, remove_dir_all, write};
+
+use crate::tests::temp_folder;
+use crate::{
+    find_project_configuration, migrate_configuration, read_configuration, write_configuration, Configuration, Error,
+    Filter, Platform, DEFAULT_JUNK, DEFAULT_PLATFORMS, PROJECT_CONFIGURATION,
+};
+
+#[test]
+fn given_the_default_configuration_it_should_read_back_what_was_written() {
+    let folder = temp_folder("config-round-trip");
+    let path = folder.join("supported-platforms.json");
+
+    write(&path, DEFAULT_PLATFORMS).unwrap();
+
+    let expected = read_configuration(&path).unwrap().platforms;
+
+    write_configuration(&path, &Configuration { platforms: expected.clone(), ..Configuration::default() }).unwrap();
+
+    let actual = read_configuration(&path).unwrap().platforms;
+
+    remove_dir_all(folder).unwrap();
+
+    assert!(!expected.is_empty());
+    assert_eq!(expected.len(), actual.len());
+    assert!(expected.iter().zip(&actual).all(|(e, a)| e.name == a.name && e.same_as(a)));
+}
+
+#[test]
+fn given_an_invalid_configuration_it_should_be_a_configuration_error() {
+    let folder = temp_folder("config-invalid");
+    let path = folder.join("supported-platforms.json");
+
+    write(&path, "{ not json").unwrap();
+
+    let actual = read_configuration(&path);
+
+    remove_dir_all(folder).unwrap();
+
+    assert!(matches!(actual, Err(Error::Config { .. })));
+}
+
+#[test]
+fn given_a_configuration_without_ignore_rules_it_should_be_a_versioned_document_of_platforms() {
+    const EXPECTED: &str = r#"{"version":2,"platforms":[{"name":"Rust","folders":["target"],"associated":["Cargo.toml"]}]}"#;
+
+    let configuration: Configuration = serde_json::from_str(EXPECTED).unwrap();
+
+    let actual = serde_json::to_string(&configuration).unwrap();
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_configuration_with_ignore_rules_it_should_be_a_document_of_platforms_and_rules() {
+    const EXPECTED: &str = r#"{"version":2,"platforms":[{"name":"Rust","folders":["target"],"associated":["Cargo.toml"]}],"ignore":["vendor/"]}"#;
+
+    let configuration: Configuration = serde_json::from_str(EXPECTED).unwrap();
+
+    let actual = serde_json::to_string(&configuration).unwrap();
+
+    assert_eq!(vec![String::from("vendor/")], configuration.ignore);
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_list_of_platforms_it_should_have_the_default_junk_files() {
+    let configuration: Configuration = serde_json::from_str("[]").unwrap();
+
+    let actual = configuration.junk.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+
+    assert_eq!(DEFAULT_JUNK.to_vec(), actual);
+}
+
+#[test]
+fn given_a_configuration_with_custom_junk_files_it_should_be_a_document_of_platforms_and_junk() {
+    const EXPECTED: &str = r#"{"version":2,"platforms":[],"junk":[".DS_Store","*.bak"]}"#;
+
+    let configuration: Configuration = serde_json::from_str(EXPECTED).unwrap();
+
+    let actual = serde_json::to_string(&configuration).unwrap();
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_list_of_platforms_it_should_migrate_to_a_versioned_document() {
+    const LEGACY: &str = r#"[{"name":"Rust","folders":["target"],"associated":["Cargo.toml"]}]"#;
+    const EXPECTED: &str = r#"{"version":2,"platforms":[{"name":"Rust","folders":["target"],"associated":["Cargo.toml"]}]}"#;
+
+    let configuration: Configuration = serde_json::from_str(LEGACY).unwrap();
+
+    let actual = serde_json::to_string(&configuration).unwrap();
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_document_without_a_version_it_should_migrate_to_a_versioned_document() {
+    const LEGACY: &str = r#"{"platforms":[],"ignore":["vendor/"]}"#;
+    const EXPECTED: &str = r#"{"version":2,"platforms":[],"ignore":["vendor/"]}"#;
+
+    let configuration: Configuration = serde_json::from_str(LEGACY).unwrap();
+
+    let actual = serde_json::to_string(&configuration).unwrap();
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_newer_version_it_should_not_be_a_configuration() {
+    let actual = serde_json::from_str::<Configuration>(r#"{"version":3,"platforms":[]}"#);
+
+    assert!(actual.is_err());
+}
+
+#[test]
+fn given_an_earlier_version_it_should_be_backed_up_and_migrated_in_place() {
+    const LEGACY: &str = r#"[{"name":"Rust","folders":["target"],"associated":["Cargo.toml"]}]"#;
+
+    let folder = temp_folder("config-migrate");
+    let path = folder.join("supported-platforms.json");
+    let expected = folder.join("supported-platforms.json.v1.bak");
+
+    write(&path, LEGACY).unwrap();
+
+    let actual = migrate_configuration(&path).unwrap();
+    let backup = read_to_string(&expected).unwrap();
+    let migrated = read_to_string(&path).unwrap();
+    let current = migrate_configuration(&path).unwrap();
+
+    remove_dir_all(folder).unwrap();
+
+    assert_eq!(Some(expected), actual);
+    assert_eq!(LEGACY, backup);
+    assert!(migrated.starts_with(r#"{"version":2,"#));
+    assert_eq!(None, current);
+}
+
+#[test]
+fn given_an_existing_configuration_it_should_be_backed_up_before_it_is_written() {
+    let folder = temp_folder("config-backup");
+    let path = folder.join("supported-platforms.json");
+
+    write(&path, "[]").unwrap();
+    write_configuration(&path, &Configuration::default()).unwrap();
+
+    let actual = read_to_string(folder.join("supported-platforms.json.bak")).unwrap();
+
+    remove_dir_all(folder).unwrap();
+
+    assert_eq!("[]", actual);
+}
+
+#[test]
+fn given_a_toml_configuration_it_should_be_written_and_read_back_as_toml() {
+    const EXPECTED: &str = r#"version = 2
+ignore = ["vendor/"]
+
+[[platforms]]
+name = "Rust"
+folders = ["target"]
+associated = ["cargo.toml"]
+"#;
+
+    let folder = temp_folder("config-toml");
+    let path = folder.join("supported-platforms.toml");
+
+    write(&path, EXPECTED).unwrap();
+
+    let configuration = read_configuration(&path).unwrap();
+
+    write_configuration(&path, &configuration).unwrap();
+
+    let actual = read_to_string(&path).unwrap();
+
+    remove_dir_all(folder).unwrap();
+
+    assert_eq!(vec![String::from("vendor/")], configuration.ignore);
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_yaml_configuration_it_should_be_written_and_read_back_as_yaml() {
+    let folder = temp_folder("config-yaml");
+    let path = folder.join("supported-platforms.yml");
+    let expected = Platform {
+        name: String::from("Rust"),
+        folders: vec![String::from("target")],
+        associated: vec![Filter::new(String::from("cargo.toml"))],
+        disabled: false,
+    };
+
+    write_configuration(&path, &Configuration { platforms: vec![expected.clone()], ..Configuration::default() })
+        .unwrap();
+
+    let document = read_to_string(&path).unwrap();
+    let actual = read_configuration(&path).unwrap().platforms;
+
+    remove_dir_all(folder).unwrap();
+
+    assert!(document.starts_with("version: 2\n"));
+    assert_eq!(1, actual.len());
+    assert!(expected.same_as(&actual[0]));
+}
+
+#[test]
+fn given_a_toml_project_configuration_above_a_scanned_path_it_should_be_found() {
+    let folder = temp_folder("config-project-toml");
+    let expected = folder.join(".cleaner.toml");
+
+    write(&expected, "version = 2\nplatforms = []\n").unwrap();
+
+    let actual = find_project_configuration(&folder);
+
+    remove_dir_all(folder).unwrap();
+
+    assert_eq!(Some(expected), actual);
+}
+
+#[test]
+fn given_a_project_configuration_above_a_scanned_path_it_should_be_found() {
+    let folder = temp_folder("config-project");
+    let scanned = folder.join("project").join("src");
+    let expected = folder.join("project").join(PROJECT_CONFIGURATION);
+
+    create_dir_all(&scanned).unwrap();
+    write(&expected, "[]").unwrap();
+    write(folder.join(PROJECT_CONFIGURATION), "[]").unwrap();
+
+    let actual = find_project_configuration(&scanned);
+
+    remove_dir_all(folder).unwrap();
+
+    assert_eq!(Some(expected), actual);
+}
@@ -0,0 +1,205 @@
+use std::collections::HashSet;
+use std::fs::{create_dir_all, remove_dir_all, write};
+use std::path::{Path, PathBuf};
+
+use walkdir::WalkDir;
+
+use crate::tests::temp_folder;
+use crate::{scan_empties, Configuration, EmptyCriteria, Platform, Traversal, DEFAULT_PLATFORMS};
+
+/// Folder names generated trees are made of, hidden, git and build artifact folders included
+const NAMES: [&str; 6] = ["src", "docs", ".cache", ".git", "target", "node_modules"];
+
+fn platforms() -> Vec<Platform> {
+    serde_json::from_str::<Configuration>(DEFAULT_PLATFORMS).unwrap().platforms
+}
+
+/// Previous empties search, starting a fresh walk under every folder it visits
+fn reference_empties(root: &Path, show_hidden: bool, skipped: &HashSet<String>) -> Vec<PathBuf> {
+    let is_folder_empty = |path: &Path| WalkDir::new(path)
+        .min_depth(1)
+        .into_iter()
+        .all(|child| !child.unwrap().file_type().is_file());
+
+    let mut empties = Vec::new();
+    let mut walker = WalkDir::new(root).into_iter();
+
+    while let Some(entry) = walker.next() {
+        let entry = entry.unwrap();
+
+        if entry.file_type().is_file() {
+            continue;
+        }
+
+        let folder_name = entry.file_name().to_string_lossy();
+        let hidden_folder = folder_name.starts_with('.');
+
+        if folder_name == ".git" || (hidden_folder && !show_hidden) || skipped.contains(folder_name.as_ref()) {
+            walker.skip_current_dir();
+            continue;
+        }
+
+        if is_folder_empty(entry.path()) {
+            walker.skip_current_dir();
+            empties.push(entry.path().to_path_buf());
+        } else if hidden_folder {
+            walker.skip_current_dir();
+        }
+    }
+
+    empties.sort();
+
+    empties
+}
+
+/// Generates a tree of folders with a few files from a seed
+fn generate_tree(folder: &Path, seed: &mut u64, depth: usize) {
+    let mut next = |bound: u64| {
+        *seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+
+        (*seed >> 33) % bound
+    };
+
+    create_dir_all(folder).unwrap();
+
+    if next(5) == 0 {
+        write(folder.join("file.txt"), "").unwrap();
+    }
+
+    if depth == 0 {
+        return;
+    }
+
+    let children = next(4);
+    let names = (0..children).map(|_| NAMES[usize::try_from(next(6)).unwrap()]).collect::<HashSet<_>>();
+
+    for name in names {
+        generate_tree(&folder.join(name), seed, depth - 1);
+    }
+}
+
+#[test]
+fn given_generated_trees_scan_empties_should_match_the_previous_implementation() {
+    let folder = temp_folder("empties-generated");
+    let platforms = platforms();
+    let skipped = platforms.iter().flat_map(|p| p.folders.iter().cloned()).collect::<HashSet<_>>();
+    let mut found = 0;
+
+    for seed in 0..32 {
+        let root = folder.join(format!("tree-{seed}"));
+
+        generate_tree(&root, &mut (seed + 1), 5);
+
+        for show_hidden in [false, true] {
+            let expected = reference_empties(&root, show_hidden, &skipped);
+
+            let criteria = EmptyCriteria::default().with_hidden(show_hidden).with_git(false, true);
+            let mut actual = scan_empties(&root, &platforms, &Traversal::default(), &criteria)
+                .map(|empty| empty.map(|empty| empty.folder))
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+
+            actual.sort();
+            found += actual.len();
+
+            assert_eq!(expected, actual, "seed {seed}, show hidden {show_hidden}");
+        }
+    }
+
+    remove_dir_all(&folder).unwrap();
+
+    assert!(found > 0);
+}
+
+#[test]
+fn given_a_nested_empty_tree_scan_empties_should_only_report_the_topmost_empty_folder() {
+    let folder = temp_folder("empties-topmost");
+    let platforms = platforms();
+
+    create_dir_all(folder.join("outer").join("middle").join("inner")).unwrap();
+    create_dir_all(folder.join("outer").join(".hidden")).unwrap();
+    create_dir_all(folder.join("kept").join("empty")).unwrap();
+    write(folder.join("kept").join("file.txt"), "").unwrap();
+
+    let actual = scan_empties(&folder, &platforms, &Traversal::default(), &EmptyCriteria::default())
+        .map(|empty| empty.map(|empty| empty.folder))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(vec![folder.join("kept").join("empty"), folder.join("outer")], actual);
+}
+
+#[test]
+fn given_a_hidden_folder_to_search_scan_empties_should_still_search_it() {
+    let folder = temp_folder("empties-hidden-root");
+    let platforms = platforms();
+    let root = folder.join(".config");
+
+    create_dir_all(root.join("empty")).unwrap();
+    write(root.join("file.txt"), "").unwrap();
+
+    let actual = scan_empties(&root, &platforms, &Traversal::default(), &EmptyCriteria::default())
+        .map(|empty| empty.map(|empty| empty.folder))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(vec![root.join("empty")], actual);
+}
+
+#[test]
+fn given_ignored_paths_scan_empties_should_not_report_the_folders_containing_them_as_empty() {
+    let folder = temp_folder("empties-ignored");
+    let platforms = platforms();
+
+    create_dir_all(folder.join("scratch").join("ignored")).unwrap();
+    create_dir_all(folder.join("logs")).unwrap();
+    write(folder.join("logs").join("debug.log"), "").unwrap();
+    create_dir_all(folder.join("other").join("child")).unwrap();
+
+    let traversal = Traversal::new(&[String::from("scratch/ignored"), String::from("*.log")]);
+    let actual = scan_empties(&folder, &platforms, &traversal, &EmptyCriteria::default())
+        .map(|empty| empty.map(|empty| empty.folder))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(vec![folder.join("other")], actual);
+}
+
+#[cfg(unix)]
+#[test]
+fn given_an_unreadable_folder_scan_empties_should_report_it_and_not_report_its_parent_as_empty() {
+    use std::fs::{read_dir, set_permissions, Permissions};
+    use std::os::unix::fs::PermissionsExt;
+
+    let folder = temp_folder("empties-unreadable");
+    let platforms = platforms();
+    let locked = folder.join("parent").join("locked");
+
+    create_dir_all(locked.join("child")).unwrap();
+    create_dir_all(folder.join("other").join("child")).unwrap();
+    set_permissions(&locked, Permissions::from_mode(0o000)).unwrap();
+
+    // privileged users read folders regardless of their permissions
+    let readable = read_dir(&locked).is_ok();
+
+    let (empties, errors): (Vec<_>, Vec<_>) =
+        scan_empties(&folder, &platforms, &Traversal::default(), &EmptyCriteria::default()).partition(Result::is_ok);
+
+    set_permissions(&locked, Permissions::from_mode(0o755)).unwrap();
+    remove_dir_all(&folder).unwrap();
+
+    if readable {
+        return;
+    }
+
+    let actual = empties.into_iter().map(|empty| empty.unwrap().folder).collect::<Vec<_>>();
+
+    assert_eq!(vec![folder.join("other")], actual);
+    assert_eq!(1, errors.len());
+}
@@ -0,0 +1,18 @@
+use std::io;
+use std::path::Path;
+
+use crate::error::Error;
+
+#[test]
+fn given_a_removal_error_it_should_describe_the_folder_and_cause() {
+    const EXPECTED: &str = r#"failed to remove "project/target": denied"#;
+
+    let err = Error::Remove {
+        path: Path::new("project").join("target"),
+        source: io::Error::new(io::ErrorKind::PermissionDenied, "denied"),
+    };
+
+    let actual = err.to_string();
+
+    assert_eq!(EXPECTED.replace('/', std::path::MAIN_SEPARATOR_STR), actual);
+}
@@ -0,0 +1,49 @@
+use std::fs::{create_dir_all, remove_dir_all, write, File, FileTimes};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use crate::fs::last_activity;
+use crate::tests::temp_folder;
+use crate::{Configuration, DEFAULT_PLATFORMS};
+
+/// Writes a file last modified at a time
+fn write_modified(path: &Path, modified: SystemTime) {
+    create_dir_all(path.parent().unwrap()).unwrap();
+    write(path, "content").unwrap();
+
+    File::options().write(true).open(path).unwrap().set_times(FileTimes::new().set_modified(modified)).unwrap();
+}
+
+#[test]
+fn given_a_project_its_activity_should_exclude_git_and_build_artifacts_at_any_depth() {
+    let platforms = serde_json::from_str::<Configuration>(DEFAULT_PLATFORMS).unwrap().platforms;
+    let folder = temp_folder("disk-activity");
+    let expected = SystemTime::UNIX_EPOCH + Duration::from_hours(24 * 365 * 30);
+    let recent = expected + Duration::from_hours(24);
+
+    write_modified(&folder.join("src").join("main.rs"), expected);
+    write_modified(&folder.join(".git").join("index"), recent);
+    write_modified(&folder.join("target").join("debug").join("app"), recent);
+    write_modified(&folder.join("web").join("node_modules").join("index.js"), recent);
+    write_modified(&folder.join("crates").join("core").join("target").join("debug").join("core"), recent);
+
+    let actual = last_activity(&folder, &platforms);
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(Some(expected), actual);
+}
+
+#[test]
+fn given_a_project_with_only_build_artifacts_it_should_have_no_activity() {
+    let platforms = serde_json::from_str::<Configuration>(DEFAULT_PLATFORMS).unwrap().platforms;
+    let folder = temp_folder("disk-no-activity");
+
+    write_modified(&folder.join("target").join("debug").join("app"), SystemTime::now());
+
+    let actual = last_activity(&folder, &platforms);
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(None, actual);
+}
@@ -0,0 +1,23 @@
+use chrono::DateTime;
+
+use crate::fs::{append_to_journal, journal_entries};
+use crate::models::{Age, JournalEntry};
+use crate::tests::data_home;
+
+#[test]
+fn given_an_entry_appended_to_the_journal_it_should_be_read_back_with_its_timestamp_and_offset() {
+    data_home();
+
+    let entry = JournalEntry::new("/home/user/journal-round-trip/target", Some(String::from("Rust")), 4096, true)
+        .with_trashed(true);
+
+    append_to_journal(&entry).unwrap();
+
+    let actual = journal_entries().unwrap().into_iter()
+        .find(|read| read.path == entry.path)
+        .unwrap();
+
+    assert_eq!(entry, actual);
+    assert!(DateTime::parse_from_rfc3339(&actual.removed).is_ok());
+    assert!(actual.is_within("1h".parse::<Age>().unwrap()));
+}
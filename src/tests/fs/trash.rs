@@ -0,0 +1,124 @@
+use std::fs::{create_dir_all, metadata, read_to_string, remove_dir_all, write, File, FileTimes};
+use std::time::{Duration, SystemTime};
+
+use crate::fs::{copy_folder, move_to_trash, restore_from_trash, trashed_items};
+use crate::tests::{data_home, temp_folder};
+
+#[test]
+fn given_a_trashed_folder_it_should_be_listed_and_restored_with_its_content() {
+    let trash = data_home().join("Trash");
+    let folder = temp_folder("trash-restore");
+    let target = folder.join("project").join("target");
+
+    create_dir_all(&target).unwrap();
+    write(target.join("app"), "binary").unwrap();
+
+    move_to_trash(&target).unwrap();
+
+    let trashed = trashed_items().unwrap().into_iter().find(|item| item.original == target).unwrap();
+    let in_trash = (target.exists(), trash.join("files").join(&trashed.name).join("app").exists());
+
+    restore_from_trash(&trashed).unwrap();
+
+    let actual = read_to_string(target.join("app")).unwrap();
+    let listed = trashed_items().unwrap().iter().any(|item| item.original == target);
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(trash, trashed.trash);
+    assert_eq!((false, true), in_trash);
+    assert_eq!("binary", actual);
+    assert!(!listed);
+}
+
+#[test]
+fn given_folders_of_the_same_name_they_should_be_trashed_under_unique_names() {
+    data_home();
+
+    let folder = temp_folder("trash-unique");
+    let targets = [folder.join("a").join("node_modules"), folder.join("b").join("node_modules")];
+
+    for target in &targets {
+        create_dir_all(target).unwrap();
+        move_to_trash(target).unwrap();
+    }
+
+    let items = trashed_items().unwrap();
+    let actual = targets.iter()
+        .map(|target| items.iter().find(|item| &item.original == target).map(|item| item.name.clone()))
+        .collect::<Option<Vec<_>>>()
+        .unwrap();
+
+    for item in items.iter().filter(|item| targets.contains(&item.original)) {
+        restore_from_trash(item).unwrap();
+    }
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_ne!(actual[0], actual[1]);
+}
+
+#[test]
+fn given_an_occupied_original_location_restore_should_fail_and_keep_the_folder_trashed() {
+    data_home();
+
+    let folder = temp_folder("trash-occupied");
+    let target = folder.join("target");
+
+    create_dir_all(&target).unwrap();
+    move_to_trash(&target).unwrap();
+    create_dir_all(&target).unwrap();
+
+    let trashed = trashed_items().unwrap().into_iter().find(|item| item.original == target).unwrap();
+    let actual = restore_from_trash(&trashed);
+    let listed = trashed_items().unwrap().iter().any(|item| item.original == target);
+
+    remove_dir_all(&target).unwrap();
+    restore_from_trash(&trashed).unwrap();
+    remove_dir_all(&folder).unwrap();
+
+    assert!(actual.is_err());
+    assert!(listed);
+}
+
+#[test]
+fn given_a_folder_copied_across_file_systems_it_should_keep_timestamps_and_hard_links() {
+    let folder = temp_folder("trash-copy");
+    let from = folder.join("from");
+    let to = folder.join("to");
+    let modified = SystemTime::UNIX_EPOCH + Duration::from_hours(24 * 365 * 30);
+
+    create_dir_all(from.join("debug")).unwrap();
+    write(from.join("debug").join("app"), "binary").unwrap();
+    std::fs::hard_link(from.join("debug").join("app"), from.join("app")).unwrap();
+
+    File::open(from.join("debug").join("app")).unwrap()
+        .set_times(FileTimes::new().set_modified(modified)).unwrap();
+    File::open(from.join("debug")).unwrap()
+        .set_times(FileTimes::new().set_modified(modified)).unwrap();
+
+    copy_folder(&from, &to).unwrap();
+
+    let actual = (
+        metadata(to.join("debug").join("app")).unwrap().modified().unwrap(),
+        metadata(to.join("debug")).unwrap().modified().unwrap(),
+    );
+    let linked = same_file(&to.join("app"), &to.join("debug").join("app"));
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!((modified, modified), actual);
+    assert!(linked);
+}
+
+#[cfg(unix)]
+fn same_file(a: &std::path::Path, b: &std::path::Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    metadata(a).unwrap().ino() == metadata(b).unwrap().ino()
+}
+
+#[cfg(not(unix))]
+const fn same_file(_a: &std::path::Path, _b: &std::path::Path) -> bool {
+    true
+}
@@ -1,4 +1,40 @@
-mod cli;
+use std::fs::{create_dir_all, remove_dir_all};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+mod config;
+mod empties;
+mod error;
+mod fs;
 mod models;
-mod utils;
+mod remove;
+mod scan;
+mod traversal;
 mod version;
+
+/// Creates a fresh temporary folder for a test
+fn temp_folder(name: &str) -> PathBuf {
+    let folder = std::env::temp_dir().join(format!("cleaner-{name}-{}", std::process::id()));
+
+    if folder.exists() {
+        remove_dir_all(&folder).unwrap();
+    }
+
+    create_dir_all(&folder).unwrap();
+
+    folder
+}
+
+/// Points `$XDG_DATA_HOME` at a temporary folder shared by all tests, tests never touch the user's trash or journal
+fn data_home() -> &'static PathBuf {
+    static DATA_HOME: OnceLock<PathBuf> = OnceLock::new();
+
+    DATA_HOME.get_or_init(|| {
+        let folder = temp_folder("data-home");
+
+        // SAFETY: set once, before any test reads it, std serializes its own access to the environment
+        unsafe { std::env::set_var("XDG_DATA_HOME", &folder) };
+
+        folder
+    })
+}
@@ -0,0 +1,77 @@
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use crate::models::{Age, AgeFilter};
+
+const DAY: u64 = 24 * 60 * 60;
+
+#[test]
+fn given_a_str_of_days_it_should_be_an_age_of_days() {
+    let expected = Age(Duration::from_secs(30 * DAY));
+    let actual = Age::from_str("30d").unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_a_str_of_weeks_it_should_be_an_age_of_weeks() {
+    let expected = Age(Duration::from_secs(6 * 7 * DAY));
+    let actual = Age::from_str(" 6W ").unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_a_str_with_an_unknown_unit_it_should_not_be_an_age() {
+    let actual = Age::from_str("6y");
+
+    assert!(actual.is_err());
+}
+
+#[test]
+fn given_a_str_without_a_number_it_should_not_be_an_age() {
+    let actual = Age::from_str("d");
+
+    assert!(actual.is_err());
+}
+
+#[test]
+fn given_an_age_of_weeks_it_should_display_days() {
+    const EXPECTED: &str = "14d";
+
+    let actual = Age(Duration::from_secs(14 * DAY + 5)).to_string();
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_an_older_than_filter_an_old_activity_should_match() {
+    let sut = AgeFilter { older_than: Some(Age(Duration::from_secs(30 * DAY))), newer_than: None };
+    let activity = SystemTime::now() - Duration::from_secs(31 * DAY);
+
+    assert!(sut.matches(activity));
+}
+
+#[test]
+fn given_an_older_than_filter_a_recent_activity_should_not_match() {
+    let sut = AgeFilter { older_than: Some(Age(Duration::from_secs(30 * DAY))), newer_than: None };
+    let activity = SystemTime::now() - Duration::from_secs(DAY);
+
+    assert!(!sut.matches(activity));
+}
+
+#[test]
+fn given_a_newer_than_filter_a_recent_activity_should_match() {
+    let sut = AgeFilter { older_than: None, newer_than: Some(Age(Duration::from_secs(7 * DAY))) };
+    let activity = SystemTime::now() - Duration::from_secs(DAY);
+
+    assert!(sut.matches(activity));
+}
+
+#[test]
+fn given_a_newer_than_filter_an_old_activity_should_not_match() {
+    let sut = AgeFilter { older_than: None, newer_than: Some(Age(Duration::from_secs(7 * DAY))) };
+    let activity = SystemTime::now() - Duration::from_secs(8 * DAY);
+
+    assert!(!sut.matches(activity));
+}
@@ -0,0 +1,16 @@
+use crate::models::ConfigurationFormat;
+
+#[test]
+fn given_configuration_file_extensions_the_format_should_be_chosen_by_extension() {
+    let expected = vec![
+        ConfigurationFormat::Json, ConfigurationFormat::Toml, ConfigurationFormat::Yaml, ConfigurationFormat::Yaml,
+        ConfigurationFormat::Json,
+    ];
+
+    let actual = ["cleaner.json", "cleaner.TOML", "cleaner.yaml", "cleaner.yml", "cleaner"]
+        .into_iter()
+        .map(ConfigurationFormat::of)
+        .collect::<Vec<_>>();
+
+    assert_eq!(expected, actual);
+}
@@ -0,0 +1,70 @@
+use std::path::PathBuf;
+
+use chrono::{Duration, FixedOffset, Local};
+
+use crate::models::{Age, JournalEntry};
+
+#[test]
+fn given_a_journal_line_without_trashed_it_should_parse_as_deleted() {
+    const LINE: &str = r#"{"removed":"2024-08-31T22:32:08","path":"/home/user/project/target","platform":"Rust","size":4096,"confirmed":true}"#;
+
+    let expected = JournalEntry {
+        removed: String::from("2024-08-31T22:32:08"),
+        path: PathBuf::from("/home/user/project/target"),
+        platform: Some(String::from("Rust")),
+        size: 4096,
+        confirmed: true,
+        trashed: false,
+    };
+
+    let actual = serde_json::from_str::<JournalEntry>(LINE).unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_an_empty_folder_entry_its_journal_line_should_omit_the_platform() {
+    let entry = JournalEntry::new("/home/user/empty", None, 0, false);
+
+    let actual = serde_json::to_string(&entry).unwrap();
+
+    assert!(!actual.contains("platform"));
+}
+
+#[test]
+fn given_a_removal_now_it_should_be_within_an_hour() {
+    let entry = JournalEntry::new("/home/user/project/target", Some(String::from("Rust")), 4096, false);
+
+    assert!(entry.is_within("1h".parse::<Age>().unwrap()));
+}
+
+#[test]
+fn given_an_old_removal_it_should_not_be_within_a_week() {
+    let entry = JournalEntry {
+        removed: String::from("2024-08-31T22:32:08"),
+        ..JournalEntry::new("/home/user/project/target", Some(String::from("Rust")), 4096, true)
+    };
+
+    assert!(!entry.is_within("1w".parse::<Age>().unwrap()));
+}
+
+#[test]
+fn given_a_recent_removal_without_an_offset_it_should_be_within_an_hour_as_local_time() {
+    let entry = JournalEntry {
+        removed: (Local::now() - Duration::minutes(30)).format("%Y-%m-%dT%H:%M:%S").to_string(),
+        ..JournalEntry::new("/home/user/project/target", Some(String::from("Rust")), 4096, true)
+    };
+
+    assert!(entry.is_within("1h".parse::<Age>().unwrap()));
+}
+
+#[test]
+fn given_removals_in_different_offsets_they_should_be_compared_as_instants() {
+    let far_east = FixedOffset::east_opt(14 * 3600).unwrap();
+    let entry = JournalEntry {
+        removed: (Local::now() - Duration::minutes(30)).with_timezone(&far_east).to_rfc3339(),
+        ..JournalEntry::new("/home/user/project/target", Some(String::from("Rust")), 4096, true)
+    };
+
+    assert!(entry.is_within("1h".parse::<Age>().unwrap()));
+}
@@ -0,0 +1,48 @@
+use std::path::PathBuf;
+
+use crate::models::{Configuration, Layer, LayeredConfiguration};
+
+/// Parses a configuration layer
+fn layer(configuration: &str) -> Configuration {
+    serde_json::from_str(configuration).unwrap()
+}
+
+#[test]
+fn given_a_later_layer_it_should_override_a_platform_of_the_same_name() {
+    const DEFAULT: &str = r#"[{"name":"Rust","folders":["target"],"associated":["cargo.toml"]}]"#;
+    const USER: &str = r#"[{"name":"rust","folders":["target","out"],"associated":["cargo.toml"]}]"#;
+
+    let user = Layer::User(PathBuf::from("user.json"));
+    let mut layered = LayeredConfiguration::new(&Layer::Default, layer(DEFAULT));
+
+    layered.apply(&user, layer(USER));
+
+    let actual = layered.origins().map(|(platform, layer)| (platform.folders.len(), layer)).collect::<Vec<_>>();
+
+    assert_eq!(vec![(2, &user)], actual);
+}
+
+#[test]
+fn given_a_disabled_platform_it_should_remove_the_platform_of_an_earlier_layer() {
+    const PROJECT: &str =
+        r#"[{"name":"Web","disabled":true},{"name":"Gradle","folders":["build"],"associated":["build.gradle"]}]"#;
+
+    let project = Layer::Project(PathBuf::from(".cleaner.json"));
+    let mut layered = LayeredConfiguration::default();
+
+    layered.apply(&project, layer(PROJECT));
+
+    let names = layered.origins().map(|(platform, _)| platform.name.as_str()).collect::<Vec<_>>();
+
+    assert!(!names.contains(&"Web"));
+    assert_eq!(Some(&"Gradle"), names.last());
+}
+
+#[test]
+fn given_layers_with_ignore_rules_it_should_apply_the_rules_of_all_layers() {
+    let mut layered = LayeredConfiguration::new(&Layer::Default, layer(r#"{"platforms":[],"ignore":["vendor/"]}"#));
+
+    layered.apply(&Layer::Project(PathBuf::from(".cleaner.json")), layer(r#"{"platforms":[],"ignore":["offline/"]}"#));
+
+    assert_eq!(vec![String::from("vendor/"), String::from("offline/")], layered.configuration.ignore);
+}
@@ -1 +1,8 @@
+mod age;
+mod format;
+mod journal;
+mod layer;
 mod platform;
+mod selection;
+mod size;
+mod trashed;
@@ -7,12 +7,14 @@ fn given_two_same_platforms_both_should_be_the_same() {
         name: String::from("Web"),
         folders: vec![String::from("node_modules")],
         associated: vec![Filter::new(String::from("package.json"))],
+        disabled: false,
     };
 
     let nodejs = Platform {
         name: String::from("NodeJS"),
         folders: vec![String::from("node_modules")],
         associated: vec![Filter::new(String::from("package.json"))],
+        disabled: false,
     };
 
     let the_same = web.same_as(&nodejs);
@@ -30,12 +32,14 @@ fn given_two_different_platforms_neither_should_be_the_same() {
         name: String::from("Web"),
         folders: vec![String::from("node_modules")],
         associated: vec![Filter::new(String::from("package.json"))],
+        disabled: false,
     };
 
     let rust = Platform {
         name: String::from("Rust"),
         folders: vec![String::from("target")],
         associated: vec![Filter::new(String::from("cargo.toml"))],
+        disabled: false,
     };
 
     let not_the_same = !web.same_as(&rust);
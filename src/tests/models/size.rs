@@ -0,0 +1,96 @@
+use crate::models::Size;
+
+#[test]
+fn given_a_size_under_a_kibibyte_it_should_display_bytes() {
+    const EXPECTED: &str = "512 B";
+
+    let actual = Size(512).to_string();
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_size_of_gibibytes_it_should_display_gibibytes_with_one_decimal() {
+    const EXPECTED: &str = "1.5 GiB";
+
+    let actual = Size(3 * 512 * 1024 * 1024).to_string();
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_size_of_kibibytes_it_should_display_kibibytes_with_one_decimal() {
+    const EXPECTED: &str = "2.0 KiB";
+
+    let actual = Size(2048).to_string();
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_multiple_sizes_their_sum_should_be_the_total() {
+    const EXPECTED: Size = Size(6);
+
+    let actual = [Size(1), Size(2), Size(3)].into_iter().sum::<Size>();
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_sizes_that_overflow_adding_them_should_saturate() {
+    const EXPECTED: Size = Size(u64::MAX);
+
+    let actual = Size(u64::MAX) + Size(1);
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_str_of_bytes_it_should_be_a_size_of_bytes() {
+    const EXPECTED: Size = Size(100);
+
+    let actual = "100".parse::<Size>().unwrap();
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_str_of_fractional_gibibytes_it_should_be_a_size_of_gibibytes() {
+    const EXPECTED: Size = Size(3 * 512 * 1024 * 1024);
+
+    let actual = "1.5GiB".parse::<Size>().unwrap();
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_str_of_megabytes_with_a_space_it_should_be_a_size_of_mebibytes() {
+    const EXPECTED: Size = Size(500 * 1024 * 1024);
+
+    let actual = "500 mb".parse::<Size>().unwrap();
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_str_with_an_unknown_unit_it_should_not_be_a_size() {
+    let actual = "10X".parse::<Size>();
+
+    assert!(actual.is_err());
+}
+
+#[test]
+fn given_each_form_of_a_unit_it_should_be_the_same_size() {
+    const EXPECTED: [Size; 4] = [Size(5 * 1024); 4];
+
+    let actual = ["5K", "5KB", "5KiB", "5 kib"].map(|size| size.parse::<Size>().unwrap());
+
+    assert_eq!(EXPECTED, actual);
+}
+
+#[test]
+fn given_a_str_with_a_malformed_unit_it_should_not_be_a_size() {
+    let actual = ["5iB", "5BB", "5IB", "5KBB", "5Ki"].map(|size| size.parse::<Size>().is_err());
+
+    assert_eq!([true; 5], actual);
+}
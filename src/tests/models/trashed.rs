@@ -0,0 +1,51 @@
+use std::path::{Path, PathBuf};
+
+use crate::models::TrashedItem;
+
+#[test]
+fn given_a_trashed_item_its_trash_info_should_parse_back_to_the_same_item() {
+    let expected = TrashedItem {
+        name: String::from("target.2"),
+        original: PathBuf::from("/home/user/my project/100%/target"),
+        deleted: String::from("2024-08-31T22:32:08"),
+        trash: PathBuf::from("/home/user/.local/share/Trash"),
+    };
+
+    let actual = TrashedItem::parse(&expected.trash, "target.2", &expected.trash_info()).unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_a_trashed_item_its_trash_info_should_percent_encode_the_path() {
+    const EXPECTED: &str = "Path=/home/user/my%20project/100%25/target\n";
+
+    let item = TrashedItem {
+        name: String::from("target"),
+        original: PathBuf::from("/home/user/my project/100%/target"),
+        deleted: String::from("2024-08-31T22:32:08"),
+        trash: PathBuf::from("/home/user/.local/share/Trash"),
+    };
+
+    let actual = item.trash_info();
+
+    assert!(actual.contains(EXPECTED));
+}
+
+#[test]
+fn given_a_trash_info_not_written_by_cleaner_it_should_not_parse() {
+    const TRASH_INFO: &str = "[Trash Info]\nPath=/home/user/notes\nDeletionDate=2024-08-31T22:32:08\n";
+
+    let actual = TrashedItem::parse(Path::new("/home/user/.local/share/Trash"), "notes", TRASH_INFO);
+
+    assert!(actual.is_none());
+}
+
+#[test]
+fn given_an_invalid_trash_info_it_should_not_parse() {
+    const TRASH_INFO: &str = "Path=/home/user/target\nDeletionDate=2024-08-31T22:32:08\nX-Cleaner=true\n";
+
+    let actual = TrashedItem::parse(Path::new("/home/user/.local/share/Trash"), "target", TRASH_INFO);
+
+    assert!(actual.is_none());
+}
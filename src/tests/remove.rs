@@ -0,0 +1,61 @@
+use std::fs::{create_dir_all, remove_dir_all, write};
+
+use crate::tests::temp_folder;
+use crate::{remove_folder, remove_folders, Removal};
+
+#[cfg(unix)]
+#[test]
+fn given_a_symlinked_folder_remove_folder_should_only_unlink_it() {
+    let folder = temp_folder("remove-symlink");
+    let cache = folder.join("cache").join("node_modules");
+    let link = folder.join("project").join("node_modules");
+
+    create_dir_all(&cache).unwrap();
+    create_dir_all(folder.join("project")).unwrap();
+    write(cache.join("index.js"), "shared").unwrap();
+    std::os::unix::fs::symlink(&cache, &link).unwrap();
+
+    remove_folder(&link, Removal::Delete).unwrap();
+
+    let actual = (link.symlink_metadata().is_ok(), cache.join("index.js").exists());
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!((false, true), actual);
+}
+
+#[test]
+fn given_a_folder_remove_folder_should_delete_it_and_its_content() {
+    let folder = temp_folder("remove-folder");
+    let target = folder.join("project").join("target");
+
+    create_dir_all(target.join("debug")).unwrap();
+    write(target.join("debug").join("app"), "binary").unwrap();
+
+    remove_folder(&target, Removal::Delete).unwrap();
+
+    let actual = target.exists();
+
+    remove_dir_all(&folder).unwrap();
+
+    assert!(!actual);
+}
+
+#[test]
+fn given_a_dry_run_remove_folders_should_leave_the_folders_in_place() {
+    let folder = temp_folder("remove-dry-run");
+    let targets = [folder.join("rust").join("target"), folder.join("web").join("node_modules")];
+
+    for target in &targets {
+        create_dir_all(target).unwrap();
+        write(target.join("artifact"), "built").unwrap();
+    }
+
+    let removed = remove_folders(&targets, Removal::DryRun).collect::<Result<Vec<_>, _>>().unwrap();
+    let actual = targets.iter().all(|target| target.join("artifact").exists());
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(targets.to_vec(), removed);
+    assert!(actual);
+}
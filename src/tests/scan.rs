@@ -0,0 +1,406 @@
+use std::fs::{create_dir_all, remove_dir_all, write};
+use std::path::Path;
+
+use git2::Repository;
+
+use crate::tests::temp_folder;
+use crate::walkers::PLACEHOLDERS;
+use crate::{
+    duplicate_roots, scan_builds, scan_empties, scan_repos, AgeFilter, Configuration, EmptyCriteria, EmptyFolder, Filter,
+    Ignored, Platform, Selection, Traversal, DEFAULT_PLATFORMS, IGNORE_FILE,
+};
+
+fn platforms() -> Vec<Platform> {
+    serde_json::from_str::<Configuration>(DEFAULT_PLATFORMS).unwrap().platforms
+}
+
+#[test]
+fn given_a_project_with_build_artifacts_scan_builds_should_classify_them() {
+    let folder = temp_folder("scan-builds");
+    let platforms = platforms();
+
+    create_dir_all(folder.join("project").join("target").join("debug")).unwrap();
+    write(folder.join("project").join("Cargo.toml"), "").unwrap();
+    create_dir_all(folder.join("other").join("target")).unwrap();
+
+    let actual = scan_builds(&folder, &Selection::All, &platforms, AgeFilter::default(), &Traversal::default(), false)
+        .map(|artifact| artifact.map(|artifact| (artifact.name.to_string(), artifact.folder)))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    let expected = vec![(
+        String::from("Rust"),
+        folder.join("project").join("target").to_string_lossy().to_string(),
+    )];
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_empty_folders_scan_empties_should_skip_build_artifacts() {
+    let folder = temp_folder("scan-empties");
+    let platforms = platforms();
+
+    create_dir_all(folder.join("empty")).unwrap();
+    create_dir_all(folder.join("project").join("target")).unwrap();
+    write(folder.join("project").join("Cargo.toml"), "").unwrap();
+
+    let actual = scan_empties(&folder, &platforms, &Traversal::default(), &EmptyCriteria::default())
+        .map(|empty| empty.map(|empty| empty.folder))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(vec![folder.join("empty")], actual);
+}
+
+#[test]
+fn given_a_cleanerignore_file_scan_builds_should_skip_matching_folders_and_record_the_rule() {
+    let folder = temp_folder("scan-cleanerignore");
+    let platforms = platforms();
+
+    for project in ["kept", "vendored"] {
+        create_dir_all(folder.join(project).join("target")).unwrap();
+        write(folder.join(project).join("Cargo.toml"), "").unwrap();
+    }
+
+    write(folder.join(IGNORE_FILE), "# offline builds\nvendored/target\n").unwrap();
+
+    let traversal = Traversal::default();
+    let mut walker = scan_builds(&folder, &Selection::All, &platforms, AgeFilter::default(), &traversal, false);
+
+    let actual = walker.by_ref()
+        .map(|artifact| artifact.map(|artifact| artifact.folder))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    let ignored = walker.ignored();
+
+    remove_dir_all(&folder).unwrap();
+
+    let expected = vec![folder.join("kept").join("target").to_string_lossy().to_string()];
+
+    assert_eq!(expected, actual);
+    assert_eq!(
+        vec![Ignored {
+            path: folder.join("vendored").join("target"),
+            rule: String::from("vendored/target"),
+            source: Some(folder.join(IGNORE_FILE)),
+        }],
+        ignored
+    );
+}
+
+#[test]
+fn given_a_configuration_ignore_rule_scan_empties_should_skip_matching_folders() {
+    let folder = temp_folder("scan-global-ignore");
+    let platforms = platforms();
+
+    create_dir_all(folder.join("empty")).unwrap();
+    create_dir_all(folder.join("placeholder")).unwrap();
+
+    let traversal = Traversal::new(&[String::from("placeholder")]);
+    let actual = scan_empties(&folder, &platforms, &traversal, &EmptyCriteria::default())
+        .map(|empty| empty.map(|empty| empty.folder))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(vec![folder.join("empty")], actual);
+}
+
+#[test]
+fn given_an_ignored_empty_folder_its_parent_should_not_be_empty() {
+    let folder = temp_folder("scan-ignored-child");
+    let platforms = platforms();
+
+    create_dir_all(folder.join("parent").join("keep")).unwrap();
+    create_dir_all(folder.join("other").join("child")).unwrap();
+
+    let traversal = Traversal::new(&[String::from("parent/keep")]);
+    let actual = scan_empties(&folder, &platforms, &traversal, &EmptyCriteria::default())
+        .map(|empty| empty.map(|empty| empty.folder))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(vec![folder.join("other")], actual);
+}
+
+#[test]
+fn given_respect_gitignore_scan_builds_should_only_include_untracked_ignored_folders() {
+    let folder = temp_folder("scan-gitignore-builds");
+    let platforms = platforms();
+
+    for project in ["ignored", "tracked", "unlisted"] {
+        create_dir_all(folder.join(project).join("target")).unwrap();
+        write(folder.join(project).join("Cargo.toml"), "").unwrap();
+    }
+
+    write(folder.join(".gitignore"), "/ignored/target/\n/tracked/target/\n").unwrap();
+    write(folder.join("tracked").join("target").join("committed.txt"), "").unwrap();
+
+    let repo = Repository::init(&folder).unwrap();
+    let mut index = repo.index().unwrap();
+
+    index.add_path(Path::new("tracked/target/committed.txt")).unwrap();
+    index.write().unwrap();
+
+    let actual = scan_builds(&folder, &Selection::All, &platforms, AgeFilter::default(), &Traversal::default(), true)
+        .map(|artifact| artifact.map(|artifact| artifact.folder))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    let expected = vec![folder.join("ignored").join("target").to_string_lossy().to_string()];
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_respect_gitignore_scan_empties_should_only_include_ignored_folders() {
+    let folder = temp_folder("scan-gitignore-empties");
+    let platforms = platforms();
+
+    create_dir_all(folder.join("scratch")).unwrap();
+    create_dir_all(folder.join("pending").join("cache")).unwrap();
+    write(folder.join(".gitignore"), "scratch/\ncache/\n").unwrap();
+
+    Repository::init(&folder).unwrap();
+
+    let criteria = EmptyCriteria::default().with_git(true, false);
+    let mut actual = scan_empties(&folder, &platforms, &Traversal::default(), &criteria)
+        .map(|empty| empty.map(|empty| empty.folder))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    actual.sort();
+
+    assert_eq!(vec![folder.join("pending").join("cache"), folder.join("scratch")], actual);
+}
+
+#[test]
+fn given_folders_with_only_junk_files_scan_empties_should_treat_them_as_empty() {
+    let folder = temp_folder("scan-junk");
+    let platforms = platforms();
+    let junk = [Filter::new(String::from(".DS_Store")), Filter::new(String::from("*.swp"))];
+
+    create_dir_all(folder.join("litter").join("nested")).unwrap();
+    write(folder.join("litter").join(".DS_Store"), "").unwrap();
+    write(folder.join("litter").join("nested").join(".notes.txt.swp"), "").unwrap();
+    create_dir_all(folder.join("kept")).unwrap();
+    write(folder.join("kept").join(".DS_Store"), "").unwrap();
+    write(folder.join("kept").join("notes.txt"), "").unwrap();
+
+    let actual = scan_empties(&folder, &platforms, &Traversal::default(), &EmptyCriteria::new(&junk))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    let expected = vec![EmptyFolder {
+        folder: folder.join("litter"),
+        junk: vec![
+            folder.join("litter").join(".DS_Store"),
+            folder.join("litter").join("nested").join(".notes.txt.swp"),
+        ],
+    }];
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_a_placeholder_file_scan_empties_should_keep_the_folders_it_marks_unless_tracked_are_included() {
+    let folder = temp_folder("scan-placeholder");
+    let platforms = platforms();
+
+    create_dir_all(folder.join("data").join("raw")).unwrap();
+    write(folder.join("data").join(PLACEHOLDERS[0]), "").unwrap();
+    create_dir_all(folder.join("other")).unwrap();
+
+    let traversal = Traversal::default();
+    let scan = |include_tracked| {
+        let criteria = EmptyCriteria::default().with_git(false, include_tracked);
+
+        scan_empties(&folder, &platforms, &traversal, &criteria)
+            .map(|empty| empty.map(|empty| empty.folder))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    };
+
+    let kept = scan(false);
+    let included = scan(true);
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(vec![folder.join("other")], kept);
+    assert_eq!(vec![folder.join("data").join("raw"), folder.join("other")], included);
+}
+
+#[test]
+fn given_a_folder_tracked_by_git_scan_empties_should_keep_it() {
+    let folder = temp_folder("scan-tracked");
+    let platforms = platforms();
+    let junk = [Filter::new(String::from("desktop.ini"))];
+
+    create_dir_all(folder.join("docs")).unwrap();
+    create_dir_all(folder.join("scratch")).unwrap();
+    write(folder.join("docs").join("desktop.ini"), "").unwrap();
+
+    let repo = Repository::init(&folder).unwrap();
+    let mut index = repo.index().unwrap();
+
+    index.add_path(Path::new("docs/desktop.ini")).unwrap();
+    index.write().unwrap();
+
+    let actual = scan_empties(&folder, &platforms, &Traversal::default(), &EmptyCriteria::new(&junk))
+        .map(|empty| empty.map(|empty| empty.folder))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(vec![folder.join("scratch")], actual);
+}
+
+#[test]
+fn given_a_repository_nested_in_another_scan_empties_should_check_its_folders_against_their_own_repository() {
+    let folder = temp_folder("scan-tracked-nested");
+    let platforms = platforms();
+    let junk = [Filter::new(String::from("desktop.ini"))];
+    let inner = folder.join("inner");
+
+    create_dir_all(folder.join("scratch")).unwrap();
+    create_dir_all(inner.join("docs")).unwrap();
+    create_dir_all(inner.join("scratch")).unwrap();
+    write(inner.join("docs").join("desktop.ini"), "").unwrap();
+
+    Repository::init(&folder).unwrap();
+
+    let repo = Repository::init(&inner).unwrap();
+    let mut index = repo.index().unwrap();
+
+    index.add_path(Path::new("docs/desktop.ini")).unwrap();
+    index.write().unwrap();
+
+    let mut actual = scan_empties(&folder, &platforms, &Traversal::default(), &EmptyCriteria::new(&junk))
+        .map(|empty| empty.map(|empty| empty.folder))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    actual.sort();
+
+    assert_eq!(vec![inner.join("scratch"), folder.join("scratch")], actual);
+}
+
+#[test]
+fn given_an_excluded_folder_scan_empties_should_treat_its_parent_as_not_empty() {
+    let folder = temp_folder("scan-empties-excluded");
+    let platforms = platforms();
+
+    create_dir_all(folder.join("home").join(".cache").join("empty")).unwrap();
+    create_dir_all(folder.join("other").join("empty")).unwrap();
+
+    let traversal = Traversal::default().with_exclude(&[".cache"]).unwrap();
+    let actual = scan_empties(&folder, &platforms, &traversal, &EmptyCriteria::default().with_hidden(true))
+        .map(|empty| empty.map(|empty| empty.folder))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(vec![folder.join("other")], actual);
+}
+
+#[test]
+fn given_depth_limits_scan_empties_should_only_include_empty_folders_within_them() {
+    let folder = temp_folder("scan-empties-depth");
+    let platforms = platforms();
+
+    create_dir_all(folder.join("shallow").join("deep").join("deeper")).unwrap();
+    create_dir_all(folder.join("full").join("nested").join("deep")).unwrap();
+    write(folder.join("full").join("file.txt"), "content").unwrap();
+
+    let traversal = Traversal::default().with_depth(2, Some(2));
+    let actual = scan_empties(&folder, &platforms, &traversal, &EmptyCriteria::default())
+        .map(|empty| empty.map(|empty| empty.folder))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    let expected = vec![folder.join("full").join("nested"), folder.join("shallow").join("deep")];
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_a_build_artifact_shallower_than_the_minimum_depth_scan_builds_should_skip_it_and_its_content() {
+    let folder = temp_folder("scan-builds-depth");
+    let platforms = platforms();
+
+    create_dir_all(folder.join("target").join("vendor").join("target")).unwrap();
+    write(folder.join("Cargo.toml"), "").unwrap();
+    write(folder.join("target").join("vendor").join("Cargo.toml"), "").unwrap();
+
+    let traversal = Traversal::default().with_depth(2, None);
+    let actual = scan_builds(&folder, &Selection::All, &platforms, AgeFilter::default(), &traversal, false)
+        .map(|artifact| artifact.map(|artifact| artifact.folder))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    assert!(actual.is_empty());
+}
+
+#[test]
+fn given_a_repository_shallower_than_the_minimum_depth_scan_repos_should_find_repositories_nested_in_it() {
+    let folder = temp_folder("scan-repos-depth");
+    let nested = folder.join("outer").join("libs").join("inner");
+
+    Repository::init(folder.join("outer")).unwrap();
+    Repository::init(&nested).unwrap();
+
+    let traversal = Traversal::default().with_depth(2, None);
+    let actual = scan_repos(&folder, &traversal)
+        .map(|repo| repo.map(|(_, path)| path))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(vec![nested], actual);
+}
+
+#[test]
+fn given_duplicate_roots_each_should_be_the_first_identical_root_and_nested_roots_should_not_be_duplicates() {
+    let folder = temp_folder("scan-roots");
+
+    create_dir_all(folder.join("work").join("project")).unwrap();
+    create_dir_all(folder.join("oss")).unwrap();
+
+    let roots = [
+        folder.join("work").join("project"),
+        folder.join("work"),
+        folder.join("oss"),
+        folder.join("work").join(".").join("project"),
+        folder.join("oss"),
+    ];
+
+    let actual = duplicate_roots(&roots);
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(vec![None, None, None, Some(0), Some(2)], actual);
+}
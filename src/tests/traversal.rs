@@ -0,0 +1,222 @@
+use std::fs::{create_dir_all, remove_dir_all, write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use rayon::ThreadPoolBuilder;
+
+use crate::tests::temp_folder;
+use crate::walkers::{Traverse, Visit};
+use crate::{Error, Traversal};
+
+fn traverse<T, F>(root: &Path, traversal: &Traversal, visitor: F) -> Vec<Result<T, Error>>
+where
+    T: Send,
+    F: Fn(&Path, usize) -> Visit<T> + Sync,
+{
+    let mut walk = Traverse::new(root, traversal);
+
+    std::iter::from_fn(|| walk.next(traversal, &visitor)).collect()
+}
+
+fn visit_all(root: &Path, threads: usize) -> Vec<PathBuf> {
+    visit_with(root, threads, &Traversal::default())
+}
+
+fn visit_with(root: &Path, threads: usize, traversal: &Traversal) -> Vec<PathBuf> {
+    let pool = ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+
+    pool.install(|| {
+        traverse(root, traversal, |folder, depth| match depth {
+            0 => Visit::Descend,
+            _ if folder.ends_with("skipped") => Visit::Skip,
+            _ if folder.ends_with("leaf") => Visit::Yield(folder.to_path_buf()),
+            _ => Visit::Descend,
+        })
+    })
+    .into_iter()
+    .collect::<Result<Vec<_>, _>>()
+    .unwrap()
+}
+
+#[test]
+fn given_a_tree_traverse_should_visit_folders_depth_first_in_file_name_order() {
+    let folder = temp_folder("traverse-order");
+
+    for path in ["b/leaf/nested", "a/c/leaf", "a/b/leaf", "skipped/leaf", "c"] {
+        create_dir_all(folder.join(path)).unwrap();
+    }
+
+    write(folder.join("a").join("leaf"), "a file, not a folder").unwrap();
+
+    let actual = visit_all(&folder, 1);
+
+    remove_dir_all(&folder).unwrap();
+
+    let expected = vec![
+        folder.join("a").join("b").join("leaf"),
+        folder.join("a").join("c").join("leaf"),
+        folder.join("b").join("leaf"),
+    ];
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_more_threads_traverse_should_visit_folders_in_the_same_order() {
+    let folder = temp_folder("traverse-threads");
+
+    for outer in 0..8 {
+        for inner in 0..8 {
+            create_dir_all(folder.join(format!("{outer}")).join(format!("{inner}")).join("leaf")).unwrap();
+        }
+    }
+
+    let expected = visit_all(&folder, 1);
+    let actual = visit_all(&folder, 8);
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(64, expected.len());
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_a_tree_traverse_should_yield_the_first_result_before_visiting_the_rest_of_the_tree() {
+    let folder = temp_folder("traverse-streamed");
+
+    for outer in 0..4 {
+        for inner in 0..4 {
+            create_dir_all(folder.join(format!("{outer}")).join(format!("{inner}")).join("leaf")).unwrap();
+        }
+    }
+
+    let visited = AtomicUsize::new(0);
+    let traversal = Traversal::default();
+    let visitor = |folder: &Path, _| {
+        visited.fetch_add(1, Ordering::Relaxed);
+
+        if folder.ends_with("leaf") { Visit::Yield(folder.to_path_buf()) } else { Visit::Descend }
+    };
+
+    let mut walk = Traverse::new(&folder, &traversal);
+    let first = walk.next(&traversal, &visitor).unwrap().unwrap();
+    let visited_first = visited.load(Ordering::Relaxed);
+    let rest = std::iter::from_fn(|| walk.next(&traversal, &visitor)).count();
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(folder.join("0").join("0").join("leaf"), first);
+    assert_eq!(15, rest);
+    assert!(visited_first < visited.load(Ordering::Relaxed));
+}
+
+#[test]
+fn given_depth_limits_traverse_should_only_yield_folders_within_them() {
+    let folder = temp_folder("traverse-depth");
+
+    for path in ["leaf/a/leaf", "a/leaf/b/leaf", "a/b/c/leaf"] {
+        create_dir_all(folder.join(path)).unwrap();
+    }
+
+    let actual = visit_with(&folder, 1, &Traversal::default().with_depth(2, Some(3)));
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(vec![folder.join("a").join("leaf")], actual);
+}
+
+#[test]
+fn given_exclude_patterns_traverse_should_never_descend_into_matching_folders() {
+    let folder = temp_folder("traverse-exclude");
+
+    for path in [".cache/leaf", "snap/x/leaf", "a/snap/leaf", "a/b/leaf", "mnt/leaf"] {
+        create_dir_all(folder.join(path)).unwrap();
+    }
+
+    let mounted = folder.join("mnt").to_string_lossy().to_string();
+    let traversal = Traversal::default().with_exclude(&[".cache", "snap", &mounted]).unwrap();
+
+    let actual = visit_with(&folder, 1, &traversal);
+
+    remove_dir_all(&folder).unwrap();
+
+    let expected = vec![folder.join("a").join("b").join("leaf")];
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn given_an_invalid_exclude_pattern_it_should_fail() {
+    let actual = Traversal::default().with_exclude(&["["]).map(|_| ()).unwrap_err().to_string();
+
+    assert_eq!("invalid exclude pattern \"[\": unclosed character class; missing ']'", actual);
+}
+
+#[cfg(unix)]
+#[test]
+fn given_symbolic_links_traverse_should_only_follow_them_if_links_are_followed() {
+    let folder = temp_folder("traverse-links");
+    let shared = temp_folder("traverse-links-shared");
+
+    create_dir_all(folder.join("a")).unwrap();
+    create_dir_all(shared.join("leaf")).unwrap();
+    std::os::unix::fs::symlink(&shared, folder.join("a").join("shared")).unwrap();
+
+    let not_followed = visit_all(&folder, 1);
+    let followed = visit_with(&folder, 1, &Traversal::default().with_links(true, false));
+
+    remove_dir_all(&folder).unwrap();
+    remove_dir_all(&shared).unwrap();
+
+    assert_eq!(Vec::<PathBuf>::new(), not_followed);
+    assert_eq!(vec![folder.join("a").join("shared").join("leaf")], followed);
+}
+
+#[cfg(unix)]
+#[test]
+fn given_a_symbolic_link_loop_traverse_should_report_it_instead_of_following_it() {
+    let folder = temp_folder("traverse-loop");
+
+    create_dir_all(folder.join("a").join("leaf")).unwrap();
+    std::os::unix::fs::symlink(&folder, folder.join("a").join("loop")).unwrap();
+
+    let actual = traverse(&folder, &Traversal::default().with_links(true, false), |folder, _| {
+        if folder.ends_with("leaf") { Visit::Yield(folder.to_path_buf()) } else { Visit::Descend }
+    });
+
+    remove_dir_all(&folder).unwrap();
+
+    let actual = actual.into_iter()
+        .map(|result| result.map_err(|err| matches!(err, Error::Loop { .. })))
+        .collect::<Vec<_>>();
+
+    assert_eq!(vec![Err(true), Ok(folder.join("a").join("leaf"))], actual);
+}
+
+#[test]
+fn given_a_cancelled_traversal_traverse_should_stop_visiting_folders() {
+    static CANCELLED: AtomicBool = AtomicBool::new(false);
+
+    let folder = temp_folder("traverse-cancel");
+
+    for path in ["a/leaf", "b/leaf", "c/leaf"] {
+        create_dir_all(folder.join(path)).unwrap();
+    }
+
+    let traversal = Traversal::default().with_cancel(|| CANCELLED.load(Ordering::Relaxed));
+    let visitor = |folder: &Path, _| {
+        if folder.ends_with("leaf") { Visit::Yield(folder.to_path_buf()) } else { Visit::Descend }
+    };
+
+    let mut walk = Traverse::new(&folder, &traversal);
+    let first = walk.next(&traversal, &visitor).unwrap().unwrap();
+
+    CANCELLED.store(true, Ordering::Relaxed);
+
+    let rest = std::iter::from_fn(|| walk.next(&traversal, &visitor)).count();
+
+    remove_dir_all(&folder).unwrap();
+
+    assert_eq!(folder.join("a").join("leaf"), first);
+    assert_eq!(0, rest);
+}
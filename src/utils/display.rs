@@ -1,6 +1,32 @@
-use std::process::exit;
+use std::process;
 use std::sync::Once;
 
+use crate::cli::exit::Exit;
+use crate::cli::record::Record;
+use crate::utils::{finish_output, output_error};
+
+/// Displays an error, as text or as a structured record depending on output format, and exits
+/// as an invalid argument or configuration
+#[inline]
+pub fn display_error_and_exit(message: &str) -> ! {
+    display_error_and_exit_with(Exit::Invalid, message);
+}
+
+/// Displays an error, as text or as a structured record depending on output format, and exits
+/// as a failure of a running command
+#[inline]
+pub fn display_failure_and_exit(message: &str) -> ! {
+    display_error_and_exit_with(Exit::Failed, message);
+}
+
+/// Displays an error, as text or as a structured record depending on output format, and exits
+pub fn display_error_and_exit_with(exit: Exit, message: &str) -> ! {
+    output_error(format!("\n{message}\n"), &Record::error(message.trim()));
+    finish_output();
+
+    process::exit(exit as i32);
+}
+
 /// Creates an easier to read comma separated output from a list
 pub fn list_output<T: AsRef<str>>(source: &[T]) -> String {
     /// Separator for creating a list for string values for display
@@ -35,10 +61,3 @@ pub fn list_output<T: AsRef<str>>(source: &[T]) -> String {
 
     output
 }
-
-#[inline]
-pub fn display_error_and_exit(message: &str) -> ! {
-    eprintln!("\n{message}\n");
-
-    exit(-1);
-}
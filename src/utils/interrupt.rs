@@ -0,0 +1,48 @@
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Once;
+
+use crate::cli::exit::Exit;
+use crate::utils::display_failure_and_exit;
+
+/// Stop searching, removing or restoring folders, the folder being handled is finished first
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Handles Ctrl-C while folders are removed or restored
+///
+/// * the first Ctrl-C stops after the folder being handled, and the summary so far is reported
+/// * a second Ctrl-C exits immediately
+/// * the handler is installed once, later calls have no effect
+pub fn handle_interrupts() {
+    static INSTALLED: Once = Once::new();
+
+    INSTALLED.call_once(|| {
+        let installed = ctrlc::set_handler(|| {
+            if INTERRUPTED.swap(true, Ordering::Relaxed) {
+                process::exit(Exit::Cancelled as i32);
+            }
+
+            eprintln!("\nInterrupted, finishing the current folder, press Ctrl-C again to exit immediately");
+        });
+
+        if let Err(err) = installed {
+            display_failure_and_exit(&format!("Failed to handle Ctrl-C: {err}"));
+        }
+    });
+}
+
+/// Stops removing or restoring folders, i.e. when a confirmation is cancelled
+pub fn interrupt() {
+    INTERRUPTED.store(true, Ordering::Relaxed);
+}
+
+/// Checks if searching, removing or restoring folders was interrupted
+pub fn is_interrupted() -> bool {
+    INTERRUPTED.load(Ordering::Relaxed)
+}
+
+/// Clears an interrupt, tests interrupting a command clear it for the tests that follow
+#[cfg(test)]
+pub fn clear_interrupt() {
+    INTERRUPTED.store(false, Ordering::Relaxed);
+}
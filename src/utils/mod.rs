@@ -1,26 +1,82 @@
-use inquire::Confirm;
+use inquire::error::InquireError;
+use inquire::{Confirm, MultiSelect};
 
-pub use display::{display_error_and_exit, list_output};
-pub use platforms::{load_supported_platforms, path_of_supported_platforms_configuration};
+pub use display::{display_error_and_exit, display_error_and_exit_with, display_failure_and_exit, list_output};
+pub use interrupt::{handle_interrupts, interrupt, is_interrupted};
+pub use output::{
+    buffered, errors_reported, finish_output, is_text_output, output, output_error, output_record,
+    output_text, set_output_format, Buffered,
+};
+pub use platforms::{
+    configuration_location, load_base_layers, load_configuration, load_configuration_of, load_user_layers,
+    path_of_supported_platforms_configuration, read_user_configuration,
+};
+#[cfg(test)]
+pub use interrupt::clear_interrupt;
+#[cfg(test)]
+pub use output::write_csv;
+#[cfg(test)]
+pub use platforms::{resolve_configuration_location, ConfigurationPath, ConfigurationSource};
+pub use report::{report_collected_errors, report_error, set_fail_fast};
+pub use roots::Roots;
+pub use traversal::{set_traversal, traversal_of};
 pub use validation::{
     validate_path, validate_platform, validate_platforms, validate_platforms_filter,
     validate_unique_values,
 };
 
+use crate::cli::exit::Exit;
+
 mod display;
+mod interrupt;
+mod output;
 mod platforms;
+mod report;
+mod roots;
+mod traversal;
 mod validation;
 
-pub fn get_confirmation(msg: &&str) -> bool {
-    let confirmation = Confirm::new(&format!("remove {msg}"))
+/// Asks a user to confirm an action, cancelling declines and interrupts the remaining actions
+pub fn get_confirmation(action: &str, msg: &str) -> bool {
+    let confirmation = Confirm::new(&format!("{action} {msg}"))
         .with_default(false)
         .with_placeholder("N")
         .prompt();
 
     match confirmation {
         Ok(answer) => answer,
+        Err(InquireError::OperationCanceled | InquireError::OperationInterrupted) => {
+            interrupt();
+
+            false
+        }
+        Err(err) => {
+            display_failure_and_exit(&format!("Exception processing input: {err}"));
+        }
+    }
+}
+
+/// Lets a user pick items from a filterable list, returns the indices of the picked items
+///
+/// * preselected items are checked when the list is shown
+pub fn pick_items(action: &str, items: Vec<String>, preselected: &[usize]) -> Vec<usize> {
+    if items.is_empty() {
+        return Vec::new();
+    }
+
+    let message = format!("Select folders to {action}:");
+    let picked = MultiSelect::new(&message, items)
+        .with_default(preselected)
+        .with_help_message("↑↓ to move, space to select, → all, ← none, type to filter, enter to confirm")
+        .raw_prompt();
+
+    match picked {
+        Ok(picked) => picked.into_iter().map(|option| option.index).collect(),
+        Err(InquireError::OperationCanceled | InquireError::OperationInterrupted) => {
+            display_error_and_exit_with(Exit::Cancelled, "Cancelled");
+        }
         Err(err) => {
-            display_error_and_exit(&format!("Exception processing input: {err}"));
+            display_failure_and_exit(&format!("Exception processing input: {err}"));
         }
     }
 }
@@ -0,0 +1,216 @@
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::io::{stderr, stdout, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use once_cell::sync::OnceCell;
+use serde_json::Value;
+
+use crate::cli::format::Format;
+use crate::cli::record::Record;
+
+/// Output format selected for the current command
+static FORMAT: OnceCell<Format> = OnceCell::new();
+
+/// Records collected for output as a single JSON array
+static RECORDS: Mutex<Vec<Record>> = Mutex::new(Vec::new());
+
+/// CSV headers already written to stdout and stderr, held while a row is written so it never precedes the header
+static CSV_HEADERS: [Mutex<bool>; 2] = [Mutex::new(false), Mutex::new(false)];
+
+thread_local! {
+    /// Output held back by the current thread, while it runs buffered
+    static BUFFER: RefCell<Option<Vec<Pending>>> = const { RefCell::new(None) };
+}
+
+/// Errors have been reported while processing a command
+static ERRORS_REPORTED: AtomicBool = AtomicBool::new(false);
+
+/// Checks if any errors have been reported while processing a command
+pub fn errors_reported() -> bool {
+    ERRORS_REPORTED.load(Ordering::Relaxed)
+}
+
+/// Sets the output format, can only be set once
+pub fn set_output_format(format: Format) {
+    let _ = FORMAT.set(format);
+}
+
+/// Checks if output is human readable text
+pub fn is_text_output() -> bool {
+    output_format() == Format::Text
+}
+
+/// Output held back while running buffered, see [`buffered`]
+enum Pending {
+    Result(String, Record),
+    Error(String, Record),
+    Text(String),
+}
+
+/// Output of a buffered run, written when flushed
+#[derive(Default)]
+pub struct Buffered(Vec<Pending>);
+
+impl Buffered {
+    /// Writes the held back output, in the order it was output
+    pub fn flush(self) {
+        for pending in self.0 {
+            match pending {
+                Pending::Result(text, record) => output(text, record),
+                Pending::Error(text, record) => output_error(text, &record),
+                Pending::Text(text) => output_text(text),
+            }
+        }
+    }
+
+    /// Texts of the held back output, in the order it was output
+    #[cfg(test)]
+    pub fn texts(&self) -> Vec<&str> {
+        self.0.iter()
+            .map(|pending| match pending {
+                Pending::Result(text, _) | Pending::Error(text, _) | Pending::Text(text) => text.as_str(),
+            })
+            .collect()
+    }
+}
+
+/// Runs a function holding back its output on the current thread, output is written when flushed
+///
+/// * lets results found in parallel be output in order
+pub fn buffered<R>(run: impl FnOnce() -> R) -> (R, Buffered) {
+    let previous = BUFFER.replace(Some(Vec::new()));
+    let result = run();
+    let pending = BUFFER.replace(previous).unwrap_or_default();
+
+    (result, Buffered(pending))
+}
+
+/// Holds back output if the current thread runs buffered, returns the output otherwise
+fn hold(pending: Pending) -> Option<Pending> {
+    BUFFER.with_borrow_mut(|buffer| match buffer {
+        Some(buffer) => {
+            buffer.push(pending);
+
+            None
+        }
+        None => Some(pending),
+    })
+}
+
+/// Outputs a result, as text or as a structured record depending on output format
+pub fn output<T: Display>(text: T, record: Record) {
+    let Some(Pending::Result(text, record)) = hold(Pending::Result(text.to_string(), record)) else {
+        return;
+    };
+
+    if is_text_output() {
+        println!("{text}");
+    } else {
+        output_record(record);
+    }
+}
+
+/// Outputs an error, as text or as a structured record to stderr depending on output format
+pub fn output_error<T: Display>(text: T, record: &Record) {
+    let Some(Pending::Error(text, record)) = hold(Pending::Error(text.to_string(), record.clone())) else {
+        return;
+    };
+
+    ERRORS_REPORTED.store(true, Ordering::Relaxed);
+
+    match output_format() {
+        Format::Text => eprintln!("{text}"),
+        Format::Json | Format::Ndjson => write_line(&mut stderr(), &json_line(&record)),
+        Format::Csv => write_csv(&mut stderr(), &CSV_HEADERS[1], &record),
+    }
+}
+
+/// Outputs a result only if output is a structured format
+pub fn output_record(record: Record) {
+    match output_format() {
+        Format::Text => {}
+        Format::Json => RECORDS.lock().unwrap_or_else(PoisonError::into_inner).push(record),
+        Format::Ndjson => write_line(&mut stdout(), &json_line(&record)),
+        Format::Csv => write_csv(&mut stdout(), &CSV_HEADERS[0], &record),
+    }
+}
+
+/// Outputs text only if output is human readable text
+pub fn output_text<T: Display>(text: T) {
+    let Some(Pending::Text(text)) = hold(Pending::Text(text.to_string())) else {
+        return;
+    };
+
+    if is_text_output() {
+        println!("{text}");
+    }
+}
+
+/// Completes output, writes collected records for JSON output
+pub fn finish_output() {
+    if output_format() == Format::Json {
+        let records = std::mem::take(&mut *RECORDS.lock().unwrap_or_else(PoisonError::into_inner));
+        let json = serde_json::to_string_pretty(&records).unwrap_or_default();
+
+        write_line(&mut stdout(), &json);
+    }
+}
+
+/// Gets the output format, defaults to text
+fn output_format() -> Format {
+    FORMAT.get().copied().unwrap_or_default()
+}
+
+/// Serializes a record as a single line of JSON
+fn json_line(record: &Record) -> String {
+    serde_json::to_string(record).unwrap_or_default()
+}
+
+/// Writes a record as CSV, writing the header first if it was not already written
+///
+/// * header and row are written while the header is held, rows of other threads wait for the header
+pub fn write_csv(writer: &mut impl Write, header: &Mutex<bool>, record: &Record) {
+    let Ok(Value::Object(fields)) = serde_json::to_value(record) else {
+        return;
+    };
+
+    let row = Record::COLUMNS.iter()
+        .map(|column| match fields.get(*column) {
+            None | Some(Value::Null) => String::new(),
+            Some(Value::String(value)) => csv_escape(value),
+            Some(Value::Array(values)) => csv_escape(
+                &values.iter().filter_map(Value::as_str).collect::<Vec<_>>().join(";")
+            ),
+            Some(value) => csv_escape(&value.to_string()),
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let mut written = header.lock().unwrap_or_else(PoisonError::into_inner);
+
+    if !*written {
+        write_line(writer, &Record::COLUMNS.join(","));
+
+        *written = true;
+    }
+
+    write_line(writer, &row);
+
+    drop(written);
+}
+
+/// Quotes a CSV value if it contains separators, quotes or line breaks
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Writes a line at once, lines of other threads are not interleaved, ignoring failures i.e. a closed pipe
+fn write_line(writer: &mut impl Write, line: &str) {
+    let _ = writer.write_all(format!("{line}\n").as_bytes());
+}
@@ -1,82 +1,261 @@
-use std::env::current_exe;
-use std::fs;
-use std::fs::{remove_file, File};
-use std::io::BufReader;
-use std::path::PathBuf;
+use std::env::{current_exe, var_os};
+use std::ffi::OsString;
+use std::fmt::{Display, Formatter};
+use std::fs::{copy, create_dir_all, rename, write};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
+use cleaner::{
+    find_configuration, find_project_configuration, migrate_configuration, read_configuration, Configuration, Layer,
+    LayeredConfiguration,
+};
 use inquire::Confirm;
 
-use crate::utils::{display_error_and_exit, validate_platforms};
-use crate::Platform;
+use crate::utils::{display_error_and_exit, display_failure_and_exit, validate_platforms};
 
-/// Loads a configuration of supported platforms
-pub fn load_supported_platforms() -> Vec<Platform> {
+/// File name of the supported platforms configuration, a TOML or YAML configuration of the same name is used instead
+const SUPPORTED_PLATFORMS_PATH: &str = "supported-platforms.json";
+
+/// Environment variable overriding the location of the configuration file
+const CLEANER_CONFIG: &str = "CLEANER_CONFIG";
+
+/// Configuration layers a user manages, shared by the configurations of all scanned paths
+static USER_LAYERS: OnceLock<LayeredConfiguration> = OnceLock::new();
+
+/// Location of the configuration file, and why it was chosen
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigurationPath {
+    /// Configuration file
+    pub path: PathBuf,
+
+    /// Why the location was chosen
+    pub source: ConfigurationSource,
+}
+
+/// Why a configuration location was chosen, in order of precedence
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ConfigurationSource {
+    /// `$CLEANER_CONFIG` names the configuration file
+    Environment,
+
+    /// `$XDG_CONFIG_HOME` is set, and `$CLEANER_CONFIG` is not
+    XdgConfigHome,
+
+    /// Neither `$CLEANER_CONFIG` nor an absolute `$XDG_CONFIG_HOME` are set
+    Home,
+}
+
+/// Loads the configuration layers of the current folder, its closest project configuration is layered last
+pub fn load_configuration() -> LayeredConfiguration {
+    load_configuration_of(Path::new("."))
+}
+
+/// Loads the configuration layers of a scanned path, its closest project configuration is layered last
+///
+/// * the user layers are loaded once, and shared by all scanned paths
+/// * project configurations of other scanned paths do not apply
+pub fn load_configuration_of(root: &Path) -> LayeredConfiguration {
+    let mut layered = USER_LAYERS.get_or_init(load_user_layers).clone();
+
+    if let Some(project) = find_project_configuration(root) {
+        let configuration = read_layer(&project);
+
+        layered.apply(&Layer::Project(project), configuration);
+    }
+
+    validate_platforms(&layered.configuration.platforms);
+
+    layered
+}
+
+/// Loads the configuration layers a user manages, the built-in defaults, system and user configuration
+pub fn load_user_layers() -> LayeredConfiguration {
+    let mut layered = load_base_layers();
+
+    if let Some(configuration) = read_user_configuration() {
+        layered.apply(&Layer::User(path_of_supported_platforms_configuration()), configuration);
+    }
+
+    layered
+}
+
+/// Loads the configuration layers below the user configuration, the built-in defaults and system configuration
+pub fn load_base_layers() -> LayeredConfiguration {
+    let mut layered = LayeredConfiguration::default();
+
+    if let Some(system) = system_configuration_path().filter(|path| path.is_file()) {
+        let configuration = read_layer(&system);
+
+        layered.apply(&Layer::System(system), configuration);
+    }
+
+    layered
+}
+
+/// Reads the user configuration, if there is one, offering to reset it if it is invalid
+///
+/// * a configuration next to the executable, from earlier versions, is copied to the user's configuration location
+/// * a configuration of an earlier version is migrated, after it is backed up
+/// * an invalid configuration is backed up before it is reset
+pub fn read_user_configuration() -> Option<Configuration> {
     let path = path_of_supported_platforms_configuration();
-    let mut retry = false;
 
-    loop {
-        if retry && path.exists() {
-            if let Err(err) = remove_file(&path) {
-                display_error_and_exit(&format!("Exception resetting configuration: {err}"));
-            }
-        }
+    if !path.exists() {
+        migrate_legacy_configuration(&path);
+    }
 
-        if !path.exists() {
-            if let Err(err) = fs::write(&path, include_str!("../../supported-platforms.json")) {
-                display_error_and_exit(&format!("Exception creating configuration file: {err}"));
-            }
-        }
+    if !path.exists() {
+        return None;
+    }
 
-        let file = match File::open(&path) {
-            Ok(file) => file,
-            Err(err) => {
-                display_error_and_exit(&format!("Exception accessing configuration file: {err}"));
-            }
-        };
+    // an invalid configuration is reported when it is read
+    if let Ok(Some(backup)) = migrate_configuration(&path) {
+        eprintln!(
+            "Migrated configuration \"{}\", the previous version is backed up to \"{}\"\n",
+            path.to_string_lossy(), backup.to_string_lossy()
+        );
+    }
 
-        let reader = BufReader::new(file);
-        let exception_message = |err| format!("Exception with configuration: {err}");
+    match read_configuration(&path) {
+        Ok(configuration) => Some(configuration),
+        Err(err) => {
+            let message = format!("Exception with {err}");
 
-        let platforms: serde_json::Result<Vec<Platform>> = serde_json::from_reader(reader);
+            eprintln!("{message}\n");
 
-        match platforms {
-            Ok(platforms) => {
-                validate_platforms(&platforms);
-                return platforms;
+            let confirmation = Confirm::new("Would you like to reset it, it will be backed up first")
+                .with_default(false)
+                .with_placeholder("N")
+                .prompt();
+
+            if !matches!(confirmation, Ok(true)) {
+                display_error_and_exit(&message);
             }
-            Err(err) if retry =>
-                display_error_and_exit(&exception_message(err)),
-            Err(err) => {
-                let message = exception_message(err);
-
-                eprintln!("{message}\n");
-
-                let confirmation = Confirm::new("Would you like to reset it, prior changes will be lost")
-                    .with_default(false)
-                    .with_placeholder("N")
-                    .prompt();
-
-                match confirmation {
-                    Ok(true) => retry = true,
-                    _ => display_error_and_exit(&message),
-                }
+
+            let mut backup = path.clone().into_os_string();
+
+            backup.push(".bak");
+
+            if let Err(err) = rename(&path, &backup) {
+                display_failure_and_exit(&format!("Exception resetting configuration: {err}"));
             }
+
+            eprintln!("Configuration backed up to \"{}\"\n", backup.to_string_lossy());
+
+            None
         }
     }
 }
 
+/// Reads a system or project configuration layer, exits if it is invalid
+fn read_layer(path: &Path) -> Configuration {
+    read_configuration(path).unwrap_or_else(|err| display_error_and_exit(&format!("Exception with {err}")))
+}
+
+/// Gets the path of the system wide configuration, shared by all users
+#[cfg(not(windows))]
+// optional on other platforms
+#[allow(clippy::unnecessary_wraps)]
+fn system_configuration_path() -> Option<PathBuf> {
+    Some(in_configuration_format(PathBuf::from("/etc/cleaner").join(SUPPORTED_PLATFORMS_PATH)))
+}
+
+/// Gets the path of the system wide configuration, shared by all users
+#[cfg(windows)]
+fn system_configuration_path() -> Option<PathBuf> {
+    var_os("ProgramData")
+        .map(|folder| in_configuration_format(PathBuf::from(folder).join("cleaner").join(SUPPORTED_PLATFORMS_PATH)))
+}
+
+/// Configuration file in the format a user chose, the JSON, TOML or YAML file that exists, JSON if none do
+fn in_configuration_format(path: PathBuf) -> PathBuf {
+    find_configuration(&path).unwrap_or(path)
+}
+
 /// Gets the path of the supported platforms configuration json file
 pub fn path_of_supported_platforms_configuration() -> PathBuf {
-    const SUPPORTED_PLATFORMS_PATH: &str = "supported-platforms.json";
+    configuration_location().path
+}
 
-    let mut path = match current_exe() {
-        Ok(path) => path,
-        Err(err) => {
-            display_error_and_exit(&format!("Exception determining path information: {err}"));
-        }
+/// Resolves the location of the configuration file from the environment, nothing is read or written
+pub fn configuration_location() -> ConfigurationPath {
+    let home = var_os("HOME").or_else(|| var_os("USERPROFILE"));
+
+    resolve_configuration_location(var_os(CLEANER_CONFIG), var_os("XDG_CONFIG_HOME"), home)
+        .unwrap_or_else(|| display_error_and_exit(&format!(
+            "Exception determining configuration location, set ${CLEANER_CONFIG} to a configuration file"
+        )))
+}
+
+/// Resolves the location of the configuration file
+///
+/// * `$CLEANER_CONFIG`, if set, names the configuration file
+/// * otherwise it is in `$XDG_CONFIG_HOME/cleaner/`, if it is an absolute path
+/// * otherwise it is in `~/.config/cleaner/`
+/// * in a cleaner folder, the configuration is `supported-platforms.json`, `.toml`, `.yaml` or `.yml`, whichever exists
+pub fn resolve_configuration_location(
+    cleaner_config: Option<OsString>, xdg_config_home: Option<OsString>, home: Option<OsString>,
+) -> Option<ConfigurationPath> {
+    let in_cleaner_folder =
+        |folder: PathBuf| in_configuration_format(folder.join("cleaner").join(SUPPORTED_PLATFORMS_PATH));
+
+    if let Some(path) = cleaner_config.filter(|path| !path.is_empty()) {
+        return Some(ConfigurationPath { path: PathBuf::from(path), source: ConfigurationSource::Environment });
+    }
+
+    if let Some(folder) = xdg_config_home.map(PathBuf::from).filter(|folder| folder.is_absolute()) {
+        return Some(ConfigurationPath { path: in_cleaner_folder(folder), source: ConfigurationSource::XdgConfigHome });
+    }
+
+    home.filter(|home| !home.is_empty()).map(|home| ConfigurationPath {
+        path: in_cleaner_folder(PathBuf::from(home).join(".config")),
+        source: ConfigurationSource::Home,
+    })
+}
+
+/// Copies a configuration next to the executable, where earlier versions kept it, to the configuration location
+///
+/// * the legacy configuration is left in place, other users of a shared install copy it on their own
+/// * a marker next to the configuration records the copy, a configuration the user reset is not copied again
+fn migrate_legacy_configuration(path: &Path) {
+    let Ok(mut legacy) = current_exe() else {
+        return;
     };
 
-    path.set_file_name(SUPPORTED_PLATFORMS_PATH);
+    legacy.set_file_name(SUPPORTED_PLATFORMS_PATH);
+
+    let mut marker = path.as_os_str().to_owned();
+
+    marker.push(".migrated");
 
-    path
+    let marker = PathBuf::from(marker);
+
+    if legacy == path || !legacy.is_file() || marker.exists() {
+        return;
+    }
+
+    let migrated = path.parent().map_or(Ok(()), create_dir_all)
+        .and_then(|()| copy(&legacy, path))
+        .and_then(|_| write(&marker, legacy.to_string_lossy().as_bytes()));
+
+    match migrated {
+        Ok(()) => eprintln!(
+            "Copied configuration \"{}\" to \"{}\"\n",
+            legacy.to_string_lossy(), path.to_string_lossy()
+        ),
+        Err(err) => display_failure_and_exit(&format!(
+            "Exception copying configuration \"{}\": {err}", legacy.to_string_lossy()
+        )),
+    }
+}
+
+impl Display for ConfigurationSource {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Environment => fmt.write_fmt(format_args!("set by ${CLEANER_CONFIG}")),
+            Self::XdgConfigHome => fmt.write_str("in $XDG_CONFIG_HOME, $CLEANER_CONFIG is not set"),
+            Self::Home =>
+                fmt.write_str("in ~/.config, neither $CLEANER_CONFIG nor an absolute $XDG_CONFIG_HOME are set"),
+        }
+    }
 }
@@ -0,0 +1,45 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use cleaner::Error;
+
+use crate::cli::exit::Exit;
+use crate::cli::record::Record;
+use crate::utils::{display_error_and_exit_with, is_text_output, output_error};
+
+/// Stop at the first error, instead of collecting errors for reporting at the end
+static FAIL_FAST: AtomicBool = AtomicBool::new(false);
+
+/// Errors collected while processing a command
+static ERRORS: Mutex<Vec<Error>> = Mutex::new(Vec::new());
+
+/// Sets whether to stop at the first error
+pub fn set_fail_fast(fail_fast: bool) {
+    FAIL_FAST.store(fail_fast, Ordering::Relaxed);
+}
+
+/// Reports an error, exits when failing fast otherwise collects it for reporting at the end
+pub fn report_error(err: Error) {
+    if FAIL_FAST.load(Ordering::Relaxed) {
+        display_error_and_exit_with(Exit::PartialFailure, &err.to_string());
+    }
+
+    ERRORS.lock().unwrap_or_else(std::sync::PoisonError::into_inner).push(err);
+}
+
+/// Outputs all collected errors
+pub fn report_collected_errors() {
+    let errors = std::mem::take(&mut *ERRORS.lock().unwrap_or_else(std::sync::PoisonError::into_inner));
+
+    if errors.is_empty() {
+        return;
+    }
+
+    if is_text_output() {
+        eprintln!("Errors:");
+    }
+
+    for err in errors {
+        output_error(format!("  - {err}"), &Record::from(&err));
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+use std::fs::canonicalize;
+use std::path::{Path, PathBuf, MAIN_SEPARATOR};
+use std::sync::{Mutex, PoisonError};
+
+use cleaner::{duplicate_roots, Configuration, Platform};
+
+use crate::utils::{load_configuration_of, output_text, validate_path};
+
+/// Distinct target paths of a command, folders found in more than one root are only handled for the first
+///
+/// * roots inside another root are searched on their own, the outer search may never reach them
+/// * each root is searched with its own configuration, its closest project configuration is layered last
+pub struct Roots<'a> {
+    paths: Vec<&'a str>,
+
+    /// Configuration of each root, in the same order
+    configurations: Vec<Configuration>,
+
+    /// Canonical paths of the folders found so far, in any root
+    found: Mutex<HashSet<PathBuf>>,
+}
+
+impl<'a> Roots<'a> {
+    /// Validates target paths and keeps the distinct ones, duplicate roots are noted
+    pub fn new(paths: &'a [String]) -> Self {
+        for path in paths {
+            validate_path(path);
+        }
+
+        let duplicates = duplicate_roots(paths);
+
+        for (path, first) in paths.iter().zip(&duplicates) {
+            if let Some(first) = first {
+                output_text(format!("\"{path}\" is the same as \"{}\"", paths[*first]));
+            }
+        }
+
+        if duplicates.iter().any(Option::is_some) {
+            output_text("");
+        }
+
+        let paths = paths.iter()
+            .zip(duplicates)
+            .filter(|(_, first)| first.is_none())
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<_>>();
+
+        Self {
+            configurations: paths.iter().map(|path| load_configuration_of(Path::new(path)).configuration).collect(),
+            paths,
+            found: Mutex::default(),
+        }
+    }
+
+    /// Configuration of a root, by its position among the distinct roots
+    pub fn configuration(&self, idx: usize) -> &Configuration {
+        &self.configurations[idx]
+    }
+
+    /// Platforms of all roots, a platform configured by more than one root is listed once, as the first configures it
+    pub fn platforms(&self) -> Vec<Platform> {
+        let mut platforms = Vec::<Platform>::new();
+
+        for platform in self.configurations.iter().flat_map(|configuration| &configuration.platforms) {
+            if !platforms.contains(platform) {
+                platforms.push(platform.clone());
+            }
+        }
+
+        platforms
+    }
+
+    /// Checks if a folder is found for the first time, folders already found in another root are not handled again
+    pub fn is_first_found(&self, path: &Path) -> bool {
+        if self.is_single() {
+            return true;
+        }
+
+        let canonical = canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+
+        self.found.lock().unwrap_or_else(PoisonError::into_inner).insert(canonical)
+    }
+
+    /// Iterates distinct roots, in the order they were given
+    pub fn iter(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.paths.iter().copied()
+    }
+
+    /// Number of distinct roots
+    pub const fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// Checks if results are displayed relative to a single root
+    pub const fn is_single(&self) -> bool {
+        self.paths.len() == 1
+    }
+
+    /// Displays a result path, relative to its root if there is only one, otherwise as found
+    pub fn display(&self, path: &Path) -> String {
+        let path = path.to_string_lossy();
+
+        if !self.is_single() {
+            return path.to_string();
+        }
+
+        let root = self.paths[0];
+        let offset = usize::from(!root.ends_with(MAIN_SEPARATOR));
+
+        path.get(root.len() + offset..).unwrap_or_default().to_string()
+    }
+}
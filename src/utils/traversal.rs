@@ -0,0 +1,37 @@
+use std::sync::OnceLock;
+
+use cleaner::{Configuration, Traversal};
+
+use crate::cli::commands::TraversalOptions;
+use crate::utils::{display_error_and_exit, is_interrupted};
+
+/// Depth limits and exclusions of folders searched by a command, ignore rules are those of each scanned path
+static TRAVERSAL: OnceLock<Traversal> = OnceLock::new();
+
+/// Sets the traversal of folders searched by a command, exits if the options are invalid
+pub fn set_traversal(options: &TraversalOptions) {
+    if options.max_depth.is_some_and(|max_depth| max_depth < options.min_depth) {
+        display_error_and_exit("--min-depth can not be greater than --max-depth");
+    }
+
+    let traversal = Traversal::default()
+        .with_depth(options.min_depth, options.max_depth)
+        .with_links(options.follow_links, options.one_file_system)
+        .with_cancel(is_interrupted)
+        .with_exclude(&options.exclude)
+        .unwrap_or_else(|err| display_error_and_exit(&err.to_string()));
+
+    let _ = TRAVERSAL.set(traversal);
+}
+
+/// Traversal of folders searched by a command, without ignore rules, defaults to searching all folders
+///
+/// * the traversal is cancelled once the command is interrupted
+fn traversal() -> &'static Traversal {
+    TRAVERSAL.get_or_init(|| Traversal::default().with_cancel(is_interrupted))
+}
+
+/// Traversal of a scanned path, skipping the paths ignored by the path's configuration
+pub fn traversal_of(configuration: &Configuration) -> Traversal {
+    traversal().clone().with_ignore(&configuration.ignore)
+}
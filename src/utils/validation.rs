@@ -1,8 +1,10 @@
 use std::collections::HashSet;
 use std::path::Path;
 
+use cleaner::{Platform, Selection};
+
 use crate::utils::{display_error_and_exit, list_output};
-use crate::{supported_platforms, utils, Platform, Selection};
+use crate::{supported_platforms, utils};
 
 /// Validates a given path exists and it is a folder
 pub fn validate_path<P: AsRef<Path>>(path: P) {
@@ -0,0 +1,120 @@
+use std::path::{Path, PathBuf};
+
+use crate::classify::{classify_folder, GitRepos};
+use crate::error::Error;
+use crate::fs::last_activity;
+use crate::ignores::IgnoreRules;
+use crate::models::{AgeFilter, BuildArtifacts, Ignored, Platform, Selection, Traversal};
+use crate::walkers::traversal::{Traverse, Visit};
+
+/// Recursively walks the folders in a path looking for build artifacts
+pub struct BuildsWalker<'a> {
+    /// Filters projects by their last activity
+    age: AgeFilter,
+
+    /// Filters supported platforms to include in iteration
+    filter: &'a Selection,
+
+    /// Repositories enclosing the searched folders, opened once per walk
+    git: GitRepos,
+
+    /// Rules of paths to skip
+    ignore: IgnoreRules,
+
+    /// Path to recursively walk
+    path: PathBuf,
+
+    /// All supported platform filter
+    platforms: &'a [Platform],
+
+    /// Only include build artifacts git would ignore in their enclosing repository
+    respect_gitignore: bool,
+
+    /// Depth limits and exclusions of the traversal
+    traversal: Traversal,
+
+    /// Traversal of the path, walked as results are iterated
+    walk: Option<Traverse<BuildArtifacts<'a>>>,
+}
+
+impl<'a> Iterator for BuildsWalker<'a> {
+    type Item = Result<BuildArtifacts<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.ignore.next_error() {
+            return Some(Err(err));
+        }
+
+        // the traversal is taken while walked, its visitor borrows the walker
+        let mut walk = self.walk.take().unwrap_or_else(|| Traverse::new(&self.path, &self.traversal));
+        let next = walk.next(&self.traversal, &|folder, _| self.visit(folder));
+
+        self.walk = Some(walk);
+
+        next.or_else(|| self.ignore.next_error().map(Err))
+    }
+}
+
+impl<'a> BuildsWalker<'a> {
+    /// Initializes a walker of a path, looking for build artifacts of selected platforms
+    pub fn new<P: AsRef<Path>>(
+        filter: &'a Selection, path: P, platforms: &'a [Platform], age: AgeFilter,
+        traversal: &Traversal, respect_gitignore: bool,
+    ) -> Self {
+        Self {
+            age,
+            filter,
+            git: GitRepos::default(),
+            ignore: IgnoreRules::new(&path, &traversal.ignore),
+            path: path.as_ref().to_path_buf(),
+            platforms,
+            respect_gitignore,
+            traversal: traversal.clone(),
+            walk: None,
+        }
+    }
+
+    /// Folders skipped by ignore rules so far, with the rule that skipped each, in path order
+    #[must_use]
+    pub fn ignored(&self) -> Vec<Ignored> {
+        self.ignore.ignored()
+    }
+
+    /// Decides whether a folder is skipped, searched or yielded as build artifacts
+    fn visit(&self, folder: &Path) -> Visit<BuildArtifacts<'a>> {
+        if self.ignore.is_ignored(folder, true) {
+            return Visit::Skip;
+        }
+
+        let Some(artifacts) = self.build_artifacts(folder) else {
+            return Visit::Descend;
+        };
+
+        if self.respect_gitignore {
+            match self.git.ignored(folder) {
+                Ok(Some(false)) => return Visit::Skip,
+                Ok(_) => {}
+                Err(source) => return Visit::Fail(Error::Git { path: folder.to_path_buf(), source }),
+            }
+        }
+
+        // a project without files of its own has no age, it never matches an age filter
+        if !self.age.is_defined() || artifacts.activity.is_some_and(|activity| self.age.matches(activity)) {
+            Visit::Yield(artifacts)
+        } else {
+            Visit::Skip
+        }
+    }
+
+    /// Determines if a folder matches a supported platform with build artifacts
+    fn build_artifacts(&self, folder: &Path) -> Option<BuildArtifacts<'a>> {
+        let platform = classify_folder(folder, self.filter, self.platforms)?;
+        let project = folder.parent()?;
+
+        Some(BuildArtifacts {
+            name: &platform.name,
+            folder: folder.to_string_lossy().to_string(),
+            activity: if self.age.is_defined() { last_activity(project, self.platforms) } else { None },
+        })
+    }
+}
@@ -0,0 +1,186 @@
+use std::path::{Path, PathBuf};
+use std::vec::IntoIter;
+
+use crate::classify::GitRepos;
+use crate::error::Error;
+use crate::ignores::IgnoreRules;
+use crate::models::{EmptyCriteria, EmptyFolder, Traversal};
+use crate::walkers::traversal::{fold, Folder};
+
+/// Names of placeholder files, marking their folder and its sub folders as intentionally empty
+pub const PLACEHOLDERS: [&str; 2] = [".gitkeep", ".keep"];
+
+/// Recursively walks the folders in a path looking for empties
+///
+/// * a folder is empty if its sub tree has no files other than junk files
+/// * ignored paths and unreadable folders are not read, folders containing them are not empty
+/// * a folder with excluded sub folders is not empty, excluded folders are never read
+/// * only the topmost empty folder of a sub tree is iterated
+/// * unless tracked folders are included, folders tracked by git or marked by a placeholder are not iterated
+/// * once the traversal is cancelled, folders are no longer read, folders containing them are not empty
+/// * results are only iterated once the whole path is folded, an empty folder is only known to be the topmost
+///   empty folder once every folder containing it is known not to be empty
+pub struct EmptiesWalker {
+    /// Empty folders to include, junk files, hidden and git tracked folders
+    criteria: EmptyCriteria,
+
+    /// Repositories enclosing the searched folders, opened once per walk
+    git: GitRepos,
+
+    /// Rules of paths to skip
+    ignore: IgnoreRules,
+
+    /// Path to recursively walk
+    path: PathBuf,
+
+    /// Results of the traversal, folded on first iteration
+    results: Option<IntoIter<Result<EmptyFolder, Error>>>,
+
+    /// Folder names that are skipped, i.e. build artifacts
+    skipped: Vec<String>,
+
+    /// Depth limits and exclusions of the traversal
+    traversal: Traversal,
+}
+
+/// Emptiness of a folder, and the results of its sub tree if the folder is searched
+struct Scanned {
+    /// Folder sub tree has no files other than junk files, nor any ignored path or unreadable folder
+    empty: bool,
+
+    /// Junk files of the sub tree, only collected while it is empty
+    junk: Vec<PathBuf>,
+
+    /// Empty folders and errors of the sub tree
+    results: Vec<Result<EmptyFolder, Error>>,
+}
+
+impl Iterator for EmptiesWalker {
+    type Item = Result<EmptyFolder, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.results.is_none() {
+            let scanned = fold(
+                &self.path, &self.traversal, |folder, _| self.enter(folder), |folder| self.scan_folder(folder),
+            );
+
+            self.results = Some(scanned.results.into_iter());
+        }
+
+        if let Some(err) = self.ignore.next_error() {
+            return Some(Err(err));
+        }
+
+        self.results.as_mut()?.next()
+    }
+}
+
+impl EmptiesWalker {
+    /// Initializes a walker of a path, looking for empty folders
+    pub fn new<P: AsRef<Path>>(path: P, skipped: Vec<String>, traversal: &Traversal, criteria: &EmptyCriteria) -> Self {
+        Self {
+            criteria: criteria.clone(),
+            git: GitRepos::default(),
+            ignore: IgnoreRules::new(&path, &traversal.ignore),
+            path: path.as_ref().to_path_buf(),
+            results: None,
+            skipped,
+            traversal: traversal.clone(),
+        }
+    }
+
+    /// Ignored folders, and folders entered once the traversal is cancelled, are not read, they are never empty
+    fn enter(&self, folder: &Path) -> Option<Scanned> {
+        (self.traversal.is_cancelled() || self.ignore.is_ignored(folder, true))
+            .then(|| Scanned { empty: false, junk: Vec::new(), results: Vec::new() })
+    }
+
+    /// Determines if a folder is empty from its content, and which results of its sub tree are kept
+    fn scan_folder(&self, folder: Folder<Scanned>) -> Scanned {
+        let empty = folder.errors.is_empty()
+            && folder.excluded.is_empty()
+            && folder.folders.iter().all(|sub_folder| sub_folder.empty)
+            && folder.entries.iter().all(|(entry, file_type)| {
+                (!file_type.is_file() || self.is_junk(entry)) && self.ignore.matched(entry, false).is_none()
+            });
+
+        let junk = if empty {
+            folder.entries.iter()
+                .filter(|(_, file_type)| file_type.is_file())
+                .map(|(entry, _)| entry.clone())
+                .chain(folder.folders.iter().flat_map(|sub_folder| sub_folder.junk.iter().cloned()))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut errors = folder.errors.into_iter().map(Err).collect::<Vec<_>>();
+        let sub_folders = folder.folders.into_iter().flat_map(|sub_folder| sub_folder.results);
+
+        // the searched path itself is never skipped by name, i.e. "."
+        let folder_name = folder.path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let hidden_folder = folder.depth > 0 && folder_name.starts_with('.');
+        let skipped_folder = folder.depth > 0 && (
+            folder_name == ".git"
+                || (hidden_folder && !self.criteria.show_hidden)
+                || self.skipped.iter().any(|skip| folder_name == skip.as_str())
+        );
+
+        // skipped folders and non empty hidden folders are not searched, their errors are still reported
+        if skipped_folder || (hidden_folder && !empty) {
+            errors.extend(sub_folders.filter(Result::is_err));
+
+            return Scanned { empty, junk, results: errors };
+        }
+
+        if !empty {
+            errors.extend(sub_folders);
+
+            return Scanned { empty, junk, results: errors };
+        }
+
+        let empty_folder = EmptyFolder { folder: folder.path.clone(), junk: junk.clone() };
+
+        let kept = if !self.traversal.reports(folder.depth) {
+            // empty folders outside of the depth limits are only searched for nested empties within them
+            Ok(true)
+        } else if self.criteria.include_tracked {
+            Ok(false)
+        } else {
+            self.is_kept(&folder.path)
+        };
+
+        let kept = match (kept, self.criteria.respect_gitignore) {
+            // empty folders git would not ignore are searched for nested empties it would
+            (Ok(false), true) => self.git.ignored(&folder.path).map(|ignored| ignored == Some(false)),
+            (kept, _) => kept,
+        };
+
+        // kept empty folders are searched for nested empties that are not
+        let results = match kept {
+            Ok(true) => sub_folders.collect(),
+            Ok(false) => vec![Ok(empty_folder)],
+            Err(source) => vec![Err(Error::Git { path: folder.path, source })],
+        };
+
+        Scanned { empty, junk, results }
+    }
+
+    /// Checks if a folder is tracked by git, or marked by a placeholder file in it or next to it
+    fn is_kept(&self, folder: &Path) -> Result<bool, git2::Error> {
+        let marked = |folder: &Path| PLACEHOLDERS.iter().any(|placeholder| folder.join(placeholder).is_file());
+
+        if marked(folder) || folder.parent().is_some_and(marked) {
+            return Ok(true);
+        }
+
+        Ok(self.git.tracked(folder)? == Some(true))
+    }
+
+    /// Checks if a file is a junk file
+    fn is_junk(&self, file: &Path) -> bool {
+        let name = file.file_name().unwrap_or_default().to_string_lossy();
+
+        self.criteria.junk.iter().any(|junk| junk.matches(&name))
+    }
+}
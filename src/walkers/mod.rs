@@ -1,7 +1,12 @@
 pub use builds_walker::BuildsWalker;
 pub use empties_walker::EmptiesWalker;
+#[cfg(test)]
+pub use empties_walker::PLACEHOLDERS;
 pub use repos_walker::ReposWalker;
+#[cfg(test)]
+pub use traversal::{Traverse, Visit};
 
 mod builds_walker;
 mod empties_walker;
 mod repos_walker;
+mod traversal;
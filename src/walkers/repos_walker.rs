@@ -0,0 +1,77 @@
+use std::fs::symlink_metadata;
+use std::path::{Path, PathBuf};
+
+use git2::Repository;
+
+use crate::error::Error;
+use crate::ignores::IgnoreRules;
+use crate::models::Traversal;
+use crate::walkers::traversal::{Traverse, Visit};
+
+/// Recursively walks the folders in a path looking for git repositories
+pub struct ReposWalker {
+    /// Rules of paths to skip
+    ignore: IgnoreRules,
+
+    /// Path to recursively walk
+    path: PathBuf,
+
+    /// Depth limits and exclusions of the traversal
+    traversal: Traversal,
+
+    /// Traversal of the path, walked as results are iterated
+    walk: Option<Traverse<PathBuf>>,
+}
+
+impl Iterator for ReposWalker {
+    type Item = Result<(Result<Repository, git2::Error>, PathBuf), Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.ignore.next_error() {
+            return Some(Err(err));
+        }
+
+        // the traversal is taken while walked, its visitor borrows the walker
+        let mut walk = self.walk.take().unwrap_or_else(|| Traverse::new(&self.path, &self.traversal));
+        let next = walk.next(&self.traversal, &|folder, depth| self.visit(folder, depth));
+
+        self.walk = Some(walk);
+
+        let repo_path = match next.or_else(|| self.ignore.next_error().map(Err))? {
+            Ok(repo_path) => repo_path,
+            Err(err) => return Some(Err(err)),
+        };
+
+        Some(Ok((Repository::open(&repo_path), repo_path)))
+    }
+}
+
+impl ReposWalker {
+    /// Initializes a walker of a path, looking for git repositories
+    pub fn new<P: AsRef<Path>>(path: P, traversal: &Traversal) -> Self {
+        Self {
+            ignore: IgnoreRules::new(&path, &traversal.ignore),
+            path: path.as_ref().to_path_buf(),
+            traversal: traversal.clone(),
+            walk: None,
+        }
+    }
+
+    /// Decides whether a folder is skipped, searched or yielded as a git repository
+    ///
+    /// * repositories shallower than the minimum depth are searched, repositories nested in them may be deep enough
+    fn visit(&self, folder: &Path, depth: usize) -> Visit<PathBuf> {
+        if self.ignore.is_ignored(folder, true) {
+            Visit::Skip
+        } else if Self::is_folder_a_repo(folder) && depth >= self.traversal.min_depth {
+            Visit::Yield(folder.to_path_buf())
+        } else {
+            Visit::Descend
+        }
+    }
+
+    /// Determines if a folder is a git repository folder
+    fn is_folder_a_repo(folder: &Path) -> bool {
+        symlink_metadata(folder.join(".git")).is_ok_and(|metadata| metadata.is_dir())
+    }
+}
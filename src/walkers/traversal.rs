@@ -0,0 +1,343 @@
+use std::fs::{canonicalize, read_dir, FileType};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use rayon::prelude::*;
+
+use crate::error::Error;
+use crate::models::Traversal;
+
+/// Decision of a traversal visitor about a folder
+pub enum Visit<T> {
+    /// Searches the content of the folder
+    Descend,
+
+    /// Skips the folder and its content
+    Skip,
+
+    /// Yields an item for the folder, skipping its content
+    Yield(T),
+
+    /// Yields an error for the folder, skipping its content
+    Fail(Error),
+}
+
+/// Content of a folder, with the folded states of its sub folders
+pub struct Folder<S> {
+    /// Folder path
+    pub path: PathBuf,
+
+    /// Depth below the traversed path
+    pub depth: usize,
+
+    /// Entries that are not folders, i.e. files and symbolic links that are not followed, in file name order
+    pub entries: Vec<(PathBuf, FileType)>,
+
+    /// Folded states of sub folders, in file name order
+    pub folders: Vec<S>,
+
+    /// Sub folders that are excluded from the traversal, or on another file system, in file name order
+    pub excluded: Vec<PathBuf>,
+
+    /// Errors reading the folder or its entries
+    pub errors: Vec<Error>,
+}
+
+/// Visits the folders of a path depth first, including the path itself, one result at a time
+///
+/// * the sub folders of a searched folder are visited, and read, in parallel on the current rayon thread pool
+/// * results are in file name order, regardless of the number of threads, each is iterated as soon as it is found
+/// * only the content of the searched folders on the path to the next result is kept, not the whole traversal
+/// * excluded folders and folders deeper than the traversal's maximum depth are never visited
+/// * folders shallower than the traversal's minimum depth are skipped instead of yielded, with their content
+/// * symbolic links are only followed if the traversal follows links, links that loop back are errors
+/// * once the traversal is cancelled, no more folders are visited and no more results are iterated
+pub struct Traverse<T> {
+    /// File system of the traversed path, only set if other file systems are not searched
+    device: Option<u64>,
+
+    /// Results and searched folders still to be iterated, the next one last
+    pending: Vec<Pending<T>>,
+
+    /// Traversed path
+    root: PathBuf,
+}
+
+/// Result, or searched folder, of a traversal still to be iterated
+enum Pending<T> {
+    /// Result of a visited folder, or an error reading a folder
+    Found(Result<T, Error>),
+
+    /// Traversed path, not visited yet
+    Root,
+
+    /// Folder whose sub folders are not visited yet, with its canonical lineage while following links
+    Searched(Folder<SubFolder>, Option<Arc<Lineage>>),
+}
+
+impl<T: Send> Traverse<T> {
+    /// Initializes a traversal of a path, no folder is visited until the first result is requested
+    pub fn new<P: AsRef<Path>>(path: P, traversal: &Traversal) -> Self {
+        let root = path.as_ref().to_path_buf();
+
+        Self { device: root_device(&root, traversal), pending: vec![Pending::Root], root }
+    }
+
+    /// Visits folders until the next result is found, none once the traversal is complete
+    ///
+    /// * the same traversal options and visitor are expected on each call
+    pub fn next<F>(&mut self, traversal: &Traversal, visitor: &F) -> Option<Result<T, Error>>
+    where
+        F: Fn(&Path, usize) -> Visit<T> + Sync,
+    {
+        let scope = Scope { device: self.device, root: &self.root, traversal };
+
+        loop {
+            if traversal.is_cancelled() {
+                self.pending.clear();
+
+                return None;
+            }
+
+            match self.pending.pop()? {
+                Pending::Found(found) => return Some(found),
+                Pending::Root => self.pending.extend(visit_folder(&self.root, 0, scope.lineage(), &scope, visitor)),
+                Pending::Searched(content, lineage) => {
+                    let visited = content.folders.into_par_iter()
+                        .map(|sub_folder| {
+                            let lineage = sub_folder.lineage(lineage.as_ref());
+
+                            visit_folder(&sub_folder.path, content.depth + 1, lineage, &scope, visitor)
+                        })
+                        .collect::<Vec<_>>();
+
+                    // errors reading a folder come before the results of its sub folders
+                    self.pending.extend(visited.into_iter().rev().flatten());
+                    self.pending.extend(content.errors.into_iter().rev().map(|err| Pending::Found(Err(err))));
+                }
+            }
+        }
+    }
+}
+
+/// Folds the folders of a path bottom up into a state, including the path itself, reading folders in parallel
+///
+/// * `enter` returns the state of a folder that should not be read, i.e. a skipped folder
+/// * each folder is read once, and folded after all of its sub folders are folded
+/// * excluded folders and folders on other file systems are never read, they are listed in their parent's content
+/// * depth limits are left to `fold`, a folder's state may depend on folders deeper than the maximum depth
+/// * symbolic links are only followed if the traversal follows links, links that loop back are errors
+/// * cancelling is left to `enter`, a cancelled folder's state depends on the fold
+pub fn fold<P, S, E, F>(path: P, traversal: &Traversal, enter: E, fold: F) -> S
+where
+    P: AsRef<Path>,
+    S: Send,
+    E: Fn(&Path, usize) -> Option<S> + Sync,
+    F: Fn(Folder<S>) -> S + Sync,
+{
+    let root = path.as_ref();
+    let scope = Scope { device: root_device(root, traversal), root, traversal };
+
+    fold_folder(root, 0, scope.lineage().as_ref(), &scope, &enter, &fold)
+}
+
+/// Scanned path and the traversal options shared by the folders of a traversal
+struct Scope<'a> {
+    /// File system of the scanned path, only set if other file systems are not searched
+    device: Option<u64>,
+    root: &'a Path,
+    traversal: &'a Traversal,
+}
+
+/// Canonical path of a folder and of the folders containing it, only tracked while following links
+struct Lineage {
+    canonical: PathBuf,
+    parent: Option<Arc<Self>>,
+}
+
+/// Sub folder to traverse, with its canonical path while following links
+struct SubFolder {
+    path: PathBuf,
+    canonical: Option<PathBuf>,
+}
+
+/// Folds the sub folders of a folder in parallel, then the folder itself
+fn fold_folder<S, E, F>(
+    folder: &Path, depth: usize, lineage: Option<&Arc<Lineage>>, scope: &Scope, enter: &E, fold: &F,
+) -> S
+where
+    S: Send,
+    E: Fn(&Path, usize) -> Option<S> + Sync,
+    F: Fn(Folder<S>) -> S + Sync,
+{
+    if let Some(state) = enter(folder, depth) {
+        return state;
+    }
+
+    let content = read_folder(folder, depth, lineage.map(Arc::as_ref), scope);
+
+    let folders = content.folders.par_iter()
+        .map(|sub_folder| {
+            let lineage = sub_folder.lineage(lineage);
+
+            fold_folder(&sub_folder.path, depth + 1, lineage.as_ref(), scope, enter, fold)
+        })
+        .collect();
+
+    fold(Folder {
+        path: content.path,
+        depth,
+        entries: content.entries,
+        folders,
+        excluded: content.excluded,
+        errors: content.errors,
+    })
+}
+
+/// Visits a folder, reading its content if the visitor descends, none if the folder is skipped
+fn visit_folder<T, F>(
+    folder: &Path, depth: usize, lineage: Option<Arc<Lineage>>, scope: &Scope, visitor: &F,
+) -> Option<Pending<T>>
+where
+    F: Fn(&Path, usize) -> Visit<T>,
+{
+    if scope.traversal.is_cancelled() {
+        return None;
+    }
+
+    match visitor(folder, depth) {
+        Visit::Descend => {}
+        Visit::Skip => return None,
+        Visit::Yield(_) if depth < scope.traversal.min_depth => return None,
+        Visit::Yield(item) => return Some(Pending::Found(Ok(item))),
+        Visit::Fail(err) => return Some(Pending::Found(Err(err))),
+    }
+
+    if scope.traversal.max_depth.is_some_and(|max_depth| depth >= max_depth) {
+        return None;
+    }
+
+    Some(Pending::Searched(read_folder(folder, depth, lineage.as_deref(), scope), lineage))
+}
+
+/// Reads the content of a folder, sub folders are not read
+fn read_folder(folder: &Path, depth: usize, lineage: Option<&Lineage>, scope: &Scope) -> Folder<SubFolder> {
+    let walk_error = |source| Error::Walk { path: folder.to_path_buf(), source };
+    let mut content = Folder {
+        path: folder.to_path_buf(),
+        depth,
+        entries: Vec::new(),
+        folders: Vec::new(),
+        excluded: Vec::new(),
+        errors: Vec::new(),
+    };
+
+    let entries = match read_dir(folder) {
+        Ok(entries) => entries,
+        Err(err) => {
+            content.errors.push(walk_error(err));
+
+            return content;
+        }
+    };
+
+    for entry in entries {
+        let (file_type, path) = match entry.and_then(|entry| Ok((entry.file_type()?, entry.path()))) {
+            Ok(entry) => entry,
+            Err(err) => {
+                content.errors.push(walk_error(err));
+
+                continue;
+            }
+        };
+
+        let linked = file_type.is_symlink() && scope.traversal.follow_links && path.is_dir();
+
+        if !file_type.is_dir() && !linked {
+            content.entries.push((path, file_type));
+        } else if scope.traversal.excludes(scope.root, &path) || !scope.same_file_system(&path) {
+            content.excluded.push(path);
+        } else if let Some(lineage) = lineage {
+            let canonical = if linked {
+                canonicalize(&path)
+            } else {
+                Ok(lineage.canonical.join(path.file_name().unwrap_or_default()))
+            };
+
+            match canonical {
+                Ok(canonical) => if let Some(ancestor) = lineage.find(&canonical) {
+                    content.errors.push(Error::Loop { path, ancestor: ancestor.to_path_buf() });
+                } else {
+                    content.folders.push(SubFolder { path, canonical: Some(canonical) });
+                },
+                Err(err) => content.errors.push(Error::Walk { path, source: err }),
+            }
+        } else {
+            content.folders.push(SubFolder { path, canonical: None });
+        }
+    }
+
+    content.folders.sort_unstable_by(|left, right| left.path.file_name().cmp(&right.path.file_name()));
+    content.entries.sort_unstable_by(|(left, _), (right, _)| left.file_name().cmp(&right.file_name()));
+    content.excluded.sort_unstable_by(|left, right| left.file_name().cmp(&right.file_name()));
+
+    content
+}
+
+impl Scope<'_> {
+    /// Lineage of the scanned path, only while following links
+    fn lineage(&self) -> Option<Arc<Lineage>> {
+        self.traversal.follow_links.then(|| Arc::new(Lineage {
+            canonical: canonicalize(self.root).unwrap_or_else(|_| self.root.to_path_buf()),
+            parent: None,
+        }))
+    }
+
+    /// Checks if a folder is on the same file system as the scanned path, always if other file systems are searched
+    fn same_file_system(&self, folder: &Path) -> bool {
+        self.device.is_none_or(|root| device(folder).is_none_or(|device| device == root))
+    }
+}
+
+impl Lineage {
+    /// Finds the folder containing this lineage's folder, or the folder itself, with a canonical path
+    fn find(&self, canonical: &Path) -> Option<&Path> {
+        let mut lineage = Some(self);
+
+        while let Some(current) = lineage {
+            if current.canonical == canonical {
+                return Some(&current.canonical);
+            }
+
+            lineage = current.parent.as_deref();
+        }
+
+        None
+    }
+}
+
+impl SubFolder {
+    /// Lineage of the sub folder, only while following links
+    fn lineage(&self, parent: Option<&Arc<Lineage>>) -> Option<Arc<Lineage>> {
+        Some(Arc::new(Lineage { canonical: self.canonical.clone()?, parent: parent.cloned() }))
+    }
+}
+
+/// Identifies the file system of a traversed path, only if other file systems are not searched
+fn root_device(root: &Path, traversal: &Traversal) -> Option<u64> {
+    if traversal.one_file_system { device(root) } else { None }
+}
+
+/// Identifies the file system of a folder, following symbolic links
+#[cfg(unix)]
+fn device(folder: &Path) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+
+    std::fs::metadata(folder).ok().map(|metadata| metadata.dev())
+}
+
+/// Identifies the file system of a folder, file systems are not distinguished on this platform
+#[cfg(not(unix))]
+fn device(_: &Path) -> Option<u64> {
+    None
+}